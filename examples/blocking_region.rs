@@ -0,0 +1,19 @@
+use tencent_cloud_sdk::blocking::{RegionService, TencentCloudClient};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // 创建阻塞客户端，依次从环境变量、配置文件和CVM实例角色读取凭证
+    let client = TencentCloudClient::from_default_chain();
+    let region_service = RegionService::new(&client);
+
+    // 查询地域列表
+    let regions = region_service.describe_regions()?;
+    println!("找到 {} 个地域:", regions.Response.RegionSet.len());
+
+    // 查询每个可用地域的可用区
+    for region in regions.Response.RegionSet.iter().filter(|r| r.RegionState == "AVAILABLE") {
+        let zones = region_service.describe_zones(&region.Region)?;
+        println!("{} ({}): {} 个可用区", region.RegionName, region.Region, zones.Response.ZoneSet.len());
+    }
+
+    Ok(())
+}
@@ -1,8 +1,7 @@
 use tencent_cloud_sdk::{
     TencentCloudClient,
-    services::cvm::instance_config::{
-        InstanceConfigService, DescribeZoneInstanceConfigInfosRequest, Filter
-    }
+    services::cvm::instance_config::{InstanceConfigService, DescribeZoneInstanceConfigInfosRequest},
+    services::common::Filter,
 };
 use std::env;
 use std::collections::HashMap;
@@ -16,14 +15,8 @@ const DEFAULT_CHARGE_TYPE: &str = "POSTPAID_BY_HOUR"; // 按量计费
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // 从环境变量读取密钥
-    let secret_id = env::var("TENCENTCLOUD_SECRET_ID")
-        .expect("请设置环境变量TENCENTCLOUD_SECRET_ID");
-    let secret_key = env::var("TENCENTCLOUD_SECRET_KEY")
-        .expect("请设置环境变量TENCENTCLOUD_SECRET_KEY");
-
-    // 创建客户端
-    let client = TencentCloudClient::new(secret_id, secret_key);
+    // 创建客户端，依次从环境变量、配置文件和CVM实例角色读取凭证
+    let client = TencentCloudClient::from_default_chain();
     
     // 创建实例配置服务
     let instance_config_service = InstanceConfigService::new(&client);
@@ -55,39 +48,24 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut filters = Vec::new();
     
     // 添加可用区过滤条件
-    filters.push(Filter {
-        Name: "zone".to_string(),
-        Values: vec![zone.clone()],
-    });
+    filters.push(Filter::new("zone", [zone.clone()]));
     
     // 添加计费类型过滤条件
-    filters.push(Filter {
-        Name: "instance-charge-type".to_string(),
-        Values: vec![charge_type.clone()],
-    });
+    filters.push(Filter::new("instance-charge-type", [charge_type.clone()]));
     
     // 如果指定了实例系列，则添加实例系列过滤条件
     if let Some(family) = instance_family {
-        filters.push(Filter {
-            Name: "instance-family".to_string(),
-            Values: vec![family],
-        });
+        filters.push(Filter::new("instance-family", [family]));
     }
     
     // 如果指定了实例类型，则添加实例类型过滤条件
     if let Some(instance_type_value) = instance_type {
-        filters.push(Filter {
-            Name: "instance-type".to_string(),
-            Values: vec![instance_type_value],
-        });
+        filters.push(Filter::new("instance-type", [instance_type_value]));
     }
     
     // 如果指定了排序键，则添加排序键过滤条件
     if let Some(sort) = sort_key {
-        filters.push(Filter {
-            Name: "sort-keys".to_string(),
-            Values: vec![sort],
-        });
+        filters.push(Filter::new("sort-keys", [sort]));
     }
     
     // 创建请求
@@ -126,7 +104,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     item.Status);
                 
                 // 如果需要输出到文件，添加到文件内容
-                if let Some(_) = output_file.as_ref() {
+                if output_file.is_some() {
                     let bandwidth = item.InstanceBandwidth.unwrap_or(0.0);
                     let pps = item.InstancePps.unwrap_or(0.0);
                     
@@ -187,8 +165,8 @@ fn parse_args(args: &[String]) -> HashMap<String, String> {
         let arg = &args[i];
         
         // 处理--开头的参数
-        if arg.starts_with("--") {
-            let key = arg[2..].to_string();
+        if let Some(stripped) = arg.strip_prefix("--") {
+            let key = stripped.to_string();
             
             // 检查是否还有下一个参数作为值
             if i + 1 < args.len() && !args[i + 1].starts_with("--") {
@@ -1,20 +1,14 @@
 use tencent_cloud_sdk::{
     TencentCloudClient,
-    services::cvm::instance_query::{InstanceQueryService, DescribeInstancesRequest, DescribeInstancesResponse},
-    services::cvm::instance::Filter
+    services::cvm::instance_query::{InstanceQueryService, DescribeInstancesRequest, DescribeInstancesResponse, InstanceFilters},
+    services::cvm::instance::InstanceState
 };
 use std::env;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // 从环境变量读取密钥
-    let secret_id = env::var("TENCENTCLOUD_SECRET_ID")
-        .expect("请设置环境变量TENCENTCLOUD_SECRET_ID");
-    let secret_key = env::var("TENCENTCLOUD_SECRET_KEY")
-        .expect("请设置环境变量TENCENTCLOUD_SECRET_KEY");
-
-    // 创建客户端
-    let client = TencentCloudClient::new(secret_id, secret_key);
+    // 创建客户端，依次从环境变量、配置文件和CVM实例角色读取凭证
+    let client = TencentCloudClient::from_default_chain();
     
     // 创建实例查询服务
     let instance_query_service = InstanceQueryService::new(&client);
@@ -58,11 +52,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 let zone = args[2].clone();
                 println!("正在查询可用区 {} 的实例", zone);
                 
-                let mut filters = Vec::new();
-                filters.push(Filter {
-                    Name: "zone".to_string(),
-                    Values: vec![zone],
-                });
+                let filters = InstanceFilters::new().zone(zone).build();
                 
                 let request = DescribeInstancesRequest {
                     InstanceIds: None,
@@ -85,11 +75,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 let name = args[2].clone();
                 println!("正在查询名称为 {} 的实例", name);
                 
-                let mut filters = Vec::new();
-                filters.push(Filter {
-                    Name: "instance-name".to_string(),
-                    Values: vec![name],
-                });
+                let filters = InstanceFilters::new().instance_name(name).build();
                 
                 let request = DescribeInstancesRequest {
                     InstanceIds: None,
@@ -113,11 +99,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 let tag_value = args[3].clone();
                 println!("正在查询标签键值对为 {}:{} 的实例", tag_key, tag_value);
                 
-                let mut filters = Vec::new();
-                filters.push(Filter {
-                    Name: format!("tag:{}", tag_key),
-                    Values: vec![tag_value],
-                });
+                let filters = InstanceFilters::new().tag(tag_key, tag_value).build();
                 
                 let request = DescribeInstancesRequest {
                     InstanceIds: None,
@@ -156,11 +138,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 let state = args[2].clone();
                 println!("正在查询状态为 {} 的实例", state);
                 
-                let mut filters = Vec::new();
-                filters.push(Filter {
-                    Name: "instance-state".to_string(),
-                    Values: vec![state],
-                });
+                let filters = InstanceFilters::new().instance_state(InstanceState::from(state.as_str())).build();
                 
                 let request = DescribeInstancesRequest {
                     InstanceIds: None,
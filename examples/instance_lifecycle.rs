@@ -1,25 +1,25 @@
 use tencent_cloud_sdk::{
     TencentCloudClient,
     services::cvm::instance_operation::{
-        InstanceOperationService, StartInstancesRequest, StopInstancesRequest, RebootInstancesRequest
-    }
+        InstanceOperationService, StartInstancesRequest, StopInstancesRequest, RebootInstancesRequest,
+        StopType, StoppedMode,
+    },
+    services::cvm::instance_waiter::InstanceWaiter,
 };
 use std::env;
+use std::time::Duration;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // 从环境变量读取密钥
-    let secret_id = env::var("TENCENTCLOUD_SECRET_ID")
-        .expect("请设置环境变量TENCENTCLOUD_SECRET_ID");
-    let secret_key = env::var("TENCENTCLOUD_SECRET_KEY")
-        .expect("请设置环境变量TENCENTCLOUD_SECRET_KEY");
-
-    // 创建客户端
-    let client = TencentCloudClient::new(secret_id, secret_key);
+    // 创建客户端，依次从环境变量、配置文件和CVM实例角色读取凭证
+    let client = TencentCloudClient::from_default_chain();
     
     // 创建实例操作服务
     let instance_operation_service = InstanceOperationService::new(&client);
     
+    // 创建实例状态等待器，最多等待5分钟
+    let waiter = InstanceWaiter::new(&client).timeout(Duration::from_secs(300));
+    
     // 设置区域
     let region = "ap-guangzhou";
     
@@ -32,7 +32,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
     
     let operation = &args[1];
-    let instance_ids: Vec<String> = args[2..].iter().cloned().collect();
+    let instance_ids: Vec<String> = args[2..].to_vec();
     
     // 确认是否继续
     println!("将对以下实例执行{}操作:", operation);
@@ -52,16 +52,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     match operation.as_str() {
         "start" => {
             // 创建启动实例请求
-            let request = StartInstancesRequest {
-                InstanceIds: instance_ids,
-            };
+            let request = StartInstancesRequest::builder().instance_ids(instance_ids.clone()).build()?;
             
             // 发送启动请求
             println!("正在启动实例...");
             match instance_operation_service.start_instances(&request, region).await {
                 Ok(_) => {
-                    println!("实例启动请求已提交成功");
-                    println!("实例将从STOPPED状态变为STARTING状态，然后再变为RUNNING状态");
+                    println!("实例启动请求已提交成功，等待实例变为RUNNING状态...");
+                    waiter.wait_until_running(&instance_ids, region).await?;
+                    println!("实例已启动");
                 },
                 Err(err) => {
                     println!("启动实例失败: {}", err);
@@ -70,19 +69,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         },
         "stop" => {
             // 创建关闭实例请求
-            let request = StopInstancesRequest {
-                InstanceIds: instance_ids,
-                StopType: Some("SOFT".to_string()),  // 软关机
-                ForceStop: None,  // 弃用的参数
-                StoppedMode: Some("KEEP_CHARGING".to_string()),  // 关机继续收费
-            };
+            let request = StopInstancesRequest::builder()
+                .instance_ids(instance_ids.clone())
+                .stop_type(StopType::Soft)  // 软关机
+                .stopped_mode(StoppedMode::KeepCharging)  // 关机继续收费
+                .build()?;
             
             // 发送关闭请求
             println!("正在关闭实例...");
             match instance_operation_service.stop_instances(&request, region).await {
                 Ok(_) => {
-                    println!("实例关闭请求已提交成功");
-                    println!("实例将从RUNNING状态变为STOPPING状态，然后再变为STOPPED状态");
+                    println!("实例关闭请求已提交成功，等待实例变为STOPPED状态...");
+                    waiter.wait_until_stopped(&instance_ids, region).await?;
+                    println!("实例已关闭");
                 },
                 Err(err) => {
                     println!("关闭实例失败: {}", err);
@@ -91,18 +90,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         },
         "reboot" => {
             // 创建重启实例请求
-            let request = RebootInstancesRequest {
-                InstanceIds: instance_ids,
-                StopType: Some("SOFT".to_string()),  // 软重启
-                ForceReboot: None,  // 弃用的参数
-            };
+            let request = RebootInstancesRequest::builder()
+                .instance_ids(instance_ids.clone())
+                .stop_type(StopType::Soft)  // 软重启
+                .build()?;
             
             // 发送重启请求
             println!("正在重启实例...");
             match instance_operation_service.reboot_instances(&request, region).await {
                 Ok(_) => {
-                    println!("实例重启请求已提交成功");
-                    println!("实例将从RUNNING状态变为REBOOTING状态，然后再变为RUNNING状态");
+                    println!("实例重启请求已提交成功，等待实例重新变为RUNNING状态...");
+                    // 重启请求提交后实例可能仍短暂处于RUNNING状态，先等待进入REBOOTING
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    waiter.wait_until_running(&instance_ids, region).await?;
+                    println!("实例已重启");
                 },
                 Err(err) => {
                     println!("重启实例失败: {}", err);
@@ -5,7 +5,7 @@ use tencent_cloud_sdk::{
     },
     services::cvm::instance::{
         Placement, SystemDisk, DataDisk, InternetAccessible, 
-        InstanceChargeType, InstanceChargePrepaid, LoginSettings,
+        InstanceChargeType, InstanceChargePrepaid, LoginSettings, RenewFlag,
         EnhancedService, RunSecurityServiceEnabled, RunMonitorServiceEnabled
     }
 };
@@ -29,14 +29,8 @@ const DEFAULT_PASSWORD: &str = "YourPassword123";
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // 从环境变量读取密钥
-    let secret_id = env::var("TENCENTCLOUD_SECRET_ID")
-        .expect("请设置环境变量TENCENTCLOUD_SECRET_ID");
-    let secret_key = env::var("TENCENTCLOUD_SECRET_KEY")
-        .expect("请设置环境变量TENCENTCLOUD_SECRET_KEY");
-
-    // 创建客户端
-    let client = TencentCloudClient::new(secret_id, secret_key);
+    // 创建客户端，依次从环境变量、配置文件和CVM实例角色读取凭证
+    let client = TencentCloudClient::from_default_chain();
     
     // 创建实例价格服务
     let instance_price_service = InstancePriceService::new(&client);
@@ -104,7 +98,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         println!("购买周期(Period): {}个月", period);
     }
     println!("实例密码: {}", password);
-    println!("");
+    println!();
     
     println!("正在查询实例价格...");
     
@@ -112,17 +106,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let request = InquiryPriceRunInstancesRequest {
         Placement: Placement {
             Zone: Some(zone),
-            ProjectId: None,
-            HostIds: None,
-            HostIps: None,
-            DedicatedClusterId: None,
+            ..Default::default()
         },
         ImageId: image_id,
         InstanceType: instance_type,
         SystemDisk: Some(SystemDisk {
             DiskType: Some(system_disk_type),
             DiskSize: Some(system_disk_size),
-            DiskId: None,
+            ..Default::default()
         }),
         DataDisks: Some(vec![
             DataDisk {
@@ -142,7 +133,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         InstanceChargePrepaid: if let InstanceChargeType::Prepaid = charge_type {
             Some(InstanceChargePrepaid {
                 Period: period,
-                RenewFlag: Some("NOTIFY_AND_AUTO_RENEW".to_string()),
+                RenewFlag: Some(RenewFlag::NotifyAndAutoRenew),
             })
         } else {
             None
@@ -150,8 +141,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         InstanceCount: Some(instance_count),
         LoginSettings: Some(LoginSettings {
             Password: Some(password),
-            KeyIds: None,
-            KeepImageLogin: None,
+            ..Default::default()
         }),
         EnhancedService: Some(EnhancedService {
             SecurityService: Some(RunSecurityServiceEnabled {
@@ -188,8 +178,8 @@ fn parse_args(args: &[String]) -> HashMap<String, String> {
         let arg = &args[i];
         
         // 处理--开头的参数
-        if arg.starts_with("--") {
-            let key = arg[2..].to_string();
+        if let Some(stripped) = arg.strip_prefix("--") {
+            let key = stripped.to_string();
             
             // 检查是否还有下一个参数作为值
             if i + 1 < args.len() && !args[i + 1].starts_with("--") {
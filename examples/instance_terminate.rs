@@ -6,14 +6,8 @@ use std::env;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // 从环境变量读取密钥
-    let secret_id = env::var("TENCENTCLOUD_SECRET_ID")
-        .expect("请设置环境变量TENCENTCLOUD_SECRET_ID");
-    let secret_key = env::var("TENCENTCLOUD_SECRET_KEY")
-        .expect("请设置环境变量TENCENTCLOUD_SECRET_KEY");
-
-    // 创建客户端
-    let client = TencentCloudClient::new(secret_id, secret_key);
+    // 创建客户端，依次从环境变量、配置文件和CVM实例角色读取凭证
+    let client = TencentCloudClient::from_default_chain();
     
     // 创建实例操作服务
     let instance_operation_service = InstanceOperationService::new(&client);
@@ -49,10 +43,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
     
     // 创建退还实例请求
-    let request = TerminateInstancesRequest {
-        InstanceIds: instance_ids,
-        ReleasePrepaidDataDisks: Some(false),  // 默认不释放包年包月数据盘
-    };
+    let request = TerminateInstancesRequest::builder()
+        .instance_ids(instance_ids)
+        .release_prepaid_data_disks(false)  // 默认不释放包年包月数据盘
+        .build()?;
     
     // 发送退还请求
     println!("正在退还实例...");
@@ -0,0 +1,85 @@
+//! 接口定义
+//!
+//! `Action`把请求参数类型与接口名称、服务、版本和响应类型关联起来，
+//! 通过`TencentCloudClient::execute`发送。SDK尚未封装的接口可以在外部实现`Action`后直接调用：
+//!
+//! ```no_run
+//! use serde::{Deserialize, Serialize};
+//! use tencent_cloud_sdk::{Action, TencentCloudClient};
+//!
+//! #[derive(Serialize)]
+//! #[allow(non_snake_case)]
+//! struct DescribeKeyPairsRequest {
+//!     Limit: i32,
+//! }
+//!
+//! #[derive(Deserialize)]
+//! #[allow(non_snake_case)]
+//! struct DescribeKeyPairsResponse {
+//!     TotalCount: i32,
+//!     RequestId: String,
+//! }
+//!
+//! impl Action for DescribeKeyPairsRequest {
+//!     const NAME: &'static str = "DescribeKeyPairs";
+//!     const SERVICE: &'static str = "cvm";
+//!     const VERSION: &'static str = "2017-03-12";
+//!     type Response = DescribeKeyPairsResponse;
+//! }
+//!
+//! # async fn run() -> tencent_cloud_sdk::error::Result<()> {
+//! let client = TencentCloudClient::from_default_chain();
+//! let response = client.execute(&DescribeKeyPairsRequest { Limit: 10 }, Some("ap-guangzhou")).await?;
+//! println!("{} {}", response.Response.TotalCount, response.Response.RequestId);
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// 腾讯云API接口
+///
+/// 由请求参数类型实现，序列化结果作为请求体发送
+pub trait Action: Serialize {
+    /// 接口名称，如`DescribeInstances`
+    const NAME: &'static str;
+
+    /// 服务名称，如`cvm`
+    const SERVICE: &'static str;
+
+    /// 接口版本，如`2017-03-12`
+    const VERSION: &'static str;
+
+    /// 响应中`Response`字段的类型
+    type Response: DeserializeOwned;
+}
+
+/// 为同一服务、同一版本的多个请求类型实现`Action`
+///
+/// ```text
+/// impl_action! {
+///     "cvm", "2017-03-12";
+///     RunInstancesRequest => RunInstancesResponse = "RunInstances";
+/// }
+/// ```
+///
+/// 不启用任何服务特性时该宏不会被使用
+#[allow(unused_macros)]
+macro_rules! impl_action {
+    (
+        $service:literal, $version:literal;
+        $($request:ty => $response:ty = $name:literal;)*
+    ) => {
+        $(
+            impl $crate::action::Action for $request {
+                const NAME: &'static str = $name;
+                const SERVICE: &'static str = $service;
+                const VERSION: &'static str = $version;
+                type Response = $response;
+            }
+        )*
+    };
+}
+
+#[allow(unused_imports)]
+pub(crate) use impl_action;
@@ -0,0 +1,77 @@
+//! 本地模拟CVM服务
+//!
+//! 用法: cargo run --features mock-server --bin mock_cvm_server -- [--bind 127.0.0.1:9000] [--secret-id ID] [--secret-key KEY] [--transition-delay-ms 2000]
+
+use std::collections::HashMap;
+use std::env;
+use std::time::Duration;
+
+use tencent_cloud_sdk::mock_server::{MockCvmServer, DEFAULT_SECRET_ID, DEFAULT_SECRET_KEY, DEFAULT_TRANSITION_DELAY};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = env::args().collect();
+    let params = parse_args(&args);
+    if params.contains_key("help") {
+        print_usage();
+        return Ok(());
+    }
+
+    let bind = params.get("bind").cloned().unwrap_or_else(|| "127.0.0.1:9000".to_string());
+    let secret_id = params.get("secret-id").cloned().unwrap_or_else(|| DEFAULT_SECRET_ID.to_string());
+    let secret_key = params.get("secret-key").cloned().unwrap_or_else(|| DEFAULT_SECRET_KEY.to_string());
+    let transition_delay = params
+        .get("transition-delay-ms")
+        .and_then(|s| s.parse::<u64>().ok())
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_TRANSITION_DELAY);
+
+    let server = MockCvmServer::builder()
+        .bind(bind)
+        .credential(secret_id.clone(), secret_key)
+        .transition_delay(transition_delay)
+        .start()
+        .await?;
+
+    println!("模拟CVM服务已启动: {}", server.get_url());
+    println!("密钥ID: {}", secret_id);
+    println!("状态变化延迟: {:?}", transition_delay);
+    println!("客户端配置: ClientConfig::new().endpoint(\"cvm\", \"{}\")", server.get_url());
+    println!("按Ctrl+C停止");
+
+    tokio::signal::ctrl_c().await?;
+    Ok(())
+}
+
+// 解析命令行参数
+fn parse_args(args: &[String]) -> HashMap<String, String> {
+    let mut params = HashMap::new();
+    let mut i = 1;
+
+    while i < args.len() {
+        if let Some(key) = args[i].strip_prefix("--") {
+            if i + 1 < args.len() && !args[i + 1].starts_with("--") {
+                params.insert(key.to_string(), args[i + 1].clone());
+                i += 2;
+            } else {
+                params.insert(key.to_string(), "true".to_string());
+                i += 1;
+            }
+        } else {
+            i += 1;
+        }
+    }
+
+    params
+}
+
+// 打印使用方法
+fn print_usage() {
+    println!("用法: cargo run --features mock-server --bin mock_cvm_server -- [参数]");
+    println!("\n可用参数:");
+    println!("  --help                       显示此帮助信息");
+    println!("  --bind <地址>                监听地址 (默认: 127.0.0.1:9000)");
+    println!("  --secret-id <密钥ID>         校验签名的密钥ID (默认: {})", DEFAULT_SECRET_ID);
+    println!("  --secret-key <密钥Key>       校验签名的密钥Key (默认: {})", DEFAULT_SECRET_KEY);
+    println!("  --transition-delay-ms <毫秒> 实例状态变化延迟 (默认: {})", DEFAULT_TRANSITION_DELAY.as_millis());
+}
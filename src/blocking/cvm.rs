@@ -0,0 +1,279 @@
+//! 阻塞的云服务器相关服务
+
+use std::time::Duration;
+
+use super::{blocking_service, Paginator, TencentCloudClient};
+use crate::error::Result;
+use crate::services::cvm::instance::{RunInstancesRequest, RunInstancesResponseType};
+use crate::services::cvm::instance_operation::{
+    ModifyInstancesAttributeRequest, ModifyInstancesAttributeResponseType, RebootInstancesRequest,
+    RebootInstancesResponseType, RenewInstancesRequest, RenewInstancesResponseType, ResetInstanceRequest,
+    ResetInstanceResponseType, ResetInstancesPasswordRequest, ResetInstancesPasswordResponseType,
+    StartInstancesRequest, StartInstancesResponseType, StopInstancesRequest, StopInstancesResponseType,
+    TerminateInstancesRequest, TerminateInstancesResponseType,
+};
+use crate::services::cvm::instance_query::{
+    DescribeInstanceFamilyConfigsRequest, DescribeInstanceFamilyConfigsResponseType,
+    DescribeInstanceTypeConfigsRequest, DescribeInstanceTypeConfigsResponseType,
+    DescribeInstancesModificationRequest, DescribeInstancesModificationResponseType,
+    DescribeInstancesOperationLimitRequest, DescribeInstancesOperationLimitResponseType, DescribeInstancesRequest,
+    DescribeInstancesResponse, DescribeInstancesResponseType, DescribeInstancesStatusRequest,
+    DescribeInstancesStatusResponse, DescribeInstancesStatusResponseType, InstanceStatus,
+};
+use crate::services::cvm::instance_price::{
+    InquiryPriceModifyInstancesChargeTypeRequest, InquiryPriceModifyInstancesChargeTypeResponseType,
+    InquiryPriceRenewInstancesRequest, InquiryPriceRenewInstancesResponseType, InquiryPriceResetInstanceRequest,
+    InquiryPriceResetInstanceResponseType, InquiryPriceResizeInstanceDisksRequest,
+    InquiryPriceResizeInstanceDisksResponseType, InquiryPriceResizeInstancesRequest,
+    InquiryPriceResizeInstancesResponseType, InquiryPriceRunInstancesRequest, InquiryPriceRunInstancesResponseType,
+    InquiryPriceTerminateInstancesRequest, InquiryPriceTerminateInstancesResponseType,
+};
+use crate::services::cvm::instance_config::{
+    CreateDisasterRecoverGroupRequest, CreateDisasterRecoverGroupResponseType, DeleteDisasterRecoverGroupsRequest,
+    DeleteDisasterRecoverGroupsResponseType, DescribeAccountQuotaRequest, DescribeAccountQuotaResponseType,
+    DescribeInstancesAttributeRequest, DescribeInstancesAttributeResponseType,
+    DescribeZoneInstanceConfigInfosRequest, DescribeZoneInstanceConfigInfosResponseType, EnterRescueModeRequest,
+    EnterRescueModeResponseType, ExitRescueModeRequest, ExitRescueModeResponseType,
+    ModifyDisasterRecoverGroupAttributeRequest, ModifyDisasterRecoverGroupAttributeResponseType,
+    ModifyInstanceDiskTypeRequest, ModifyInstanceDiskTypeResponseType, ModifyInstancesChargeTypeRequest,
+    ModifyInstancesChargeTypeResponseType, ModifyInstancesProjectRequest, ModifyInstancesProjectResponseType,
+    ModifyInstancesRenewFlagRequest, ModifyInstancesRenewFlagResponseType, ResizeInstancesRequest,
+    ResizeInstancesResponseType,
+};
+
+blocking_service! {
+    /// 阻塞的实例服务
+    InstanceService => crate::services::cvm::instance::InstanceService {
+        /// 创建一个或多个指定配置的实例
+        fn run_instances(&self, request: &RunInstancesRequest, region: &str) -> RunInstancesResponseType;
+    }
+}
+
+blocking_service! {
+    /// 阻塞的实例操作服务
+    InstanceOperationService => crate::services::cvm::instance_operation::InstanceOperationService {
+        /// 启动实例
+        fn start_instances(&self, request: &StartInstancesRequest, region: &str) -> StartInstancesResponseType;
+        /// 重启实例
+        fn reboot_instances(&self, request: &RebootInstancesRequest, region: &str) -> RebootInstancesResponseType;
+        /// 关闭实例
+        fn stop_instances(&self, request: &StopInstancesRequest, region: &str) -> StopInstancesResponseType;
+        /// 重置实例密码
+        fn reset_instances_password(&self, request: &ResetInstancesPasswordRequest, region: &str) -> ResetInstancesPasswordResponseType;
+        /// 修改实例的属性
+        fn modify_instances_attribute(&self, request: &ModifyInstancesAttributeRequest, region: &str) -> ModifyInstancesAttributeResponseType;
+        /// 续费实例
+        fn renew_instances(&self, request: &RenewInstancesRequest, region: &str) -> RenewInstancesResponseType;
+        /// 重装实例
+        fn reset_instance(&self, request: &ResetInstanceRequest, region: &str) -> ResetInstanceResponseType;
+        /// 退还实例
+        fn terminate_instances(&self, request: &TerminateInstancesRequest, region: &str) -> TerminateInstancesResponseType;
+    }
+}
+
+blocking_service! {
+    /// 阻塞的实例查询服务
+    InstanceQueryService => crate::services::cvm::instance_query::InstanceQueryService {
+        /// 查询实例列表
+        fn describe_instances(&self, request: &DescribeInstancesRequest, region: &str) -> DescribeInstancesResponseType;
+        /// 查看实例状态列表
+        fn describe_instances_status(&self, request: &DescribeInstancesStatusRequest, region: &str) -> DescribeInstancesStatusResponseType;
+        /// 查询实例可调整配置
+        fn describe_instances_modification(&self, request: &DescribeInstancesModificationRequest, region: &str) -> DescribeInstancesModificationResponseType;
+        /// 查询实例操作限制
+        fn describe_instances_operation_limit(&self, request: &DescribeInstancesOperationLimitRequest, region: &str) -> DescribeInstancesOperationLimitResponseType;
+        /// 查询所支持的实例机型族信息
+        fn describe_instance_family_configs(&self, request: &DescribeInstanceFamilyConfigsRequest, region: &str) -> DescribeInstanceFamilyConfigsResponseType;
+        /// 查询实例机型列表
+        fn describe_instance_type_configs(&self, request: &DescribeInstanceTypeConfigsRequest, region: &str) -> DescribeInstanceTypeConfigsResponseType;
+    }
+}
+
+blocking_service! {
+    /// 阻塞的实例询价服务
+    InstancePriceService => crate::services::cvm::instance_price::InstancePriceService {
+        /// 创建实例询价
+        fn inquiry_price_run_instances(&self, request: &InquiryPriceRunInstancesRequest, region: &str) -> InquiryPriceRunInstancesResponseType;
+        /// 续费实例询价
+        fn inquiry_price_renew_instances(&self, request: &InquiryPriceRenewInstancesRequest, region: &str) -> InquiryPriceRenewInstancesResponseType;
+        /// 重装实例询价
+        fn inquiry_price_reset_instance(&self, request: &InquiryPriceResetInstanceRequest, region: &str) -> InquiryPriceResetInstanceResponseType;
+        /// 调整实例配置询价
+        fn inquiry_price_resize_instances(&self, request: &InquiryPriceResizeInstancesRequest, region: &str) -> InquiryPriceResizeInstancesResponseType;
+        /// 修改实例计费模式询价
+        fn inquiry_price_modify_instances_charge_type(&self, request: &InquiryPriceModifyInstancesChargeTypeRequest, region: &str) -> InquiryPriceModifyInstancesChargeTypeResponseType;
+        /// 扩容实例磁盘询价
+        fn inquiry_price_resize_instance_disks(&self, request: &InquiryPriceResizeInstanceDisksRequest, region: &str) -> InquiryPriceResizeInstanceDisksResponseType;
+        /// 退还实例询价
+        fn inquiry_price_terminate_instances(&self, request: &InquiryPriceTerminateInstancesRequest, region: &str) -> InquiryPriceTerminateInstancesResponseType;
+    }
+}
+
+blocking_service! {
+    /// 阻塞的实例配置服务
+    InstanceConfigService => crate::services::cvm::instance_config::InstanceConfigService {
+        /// 查询用户配额详情
+        fn describe_account_quota(&self, request: &DescribeAccountQuotaRequest, region: &str) -> DescribeAccountQuotaResponseType;
+        /// 创建分散置放群组
+        fn create_disaster_recover_group(&self, request: &CreateDisasterRecoverGroupRequest, region: &str) -> CreateDisasterRecoverGroupResponseType;
+        /// 调整实例配置
+        fn resize_instances(&self, request: &ResizeInstancesRequest, region: &str) -> ResizeInstancesResponseType;
+        /// 修改实例所属项目
+        fn modify_instances_project(&self, request: &ModifyInstancesProjectRequest, region: &str) -> ModifyInstancesProjectResponseType;
+        /// 调整实例分散置放群组
+        fn modify_disaster_recover_group_attribute(&self, request: &ModifyDisasterRecoverGroupAttributeRequest, region: &str) -> ModifyDisasterRecoverGroupAttributeResponseType;
+        /// 删除分散置放群组
+        fn delete_disaster_recover_groups(&self, request: &DeleteDisasterRecoverGroupsRequest, region: &str) -> DeleteDisasterRecoverGroupsResponseType;
+        /// 修改实例计费模式
+        fn modify_instances_charge_type(&self, request: &ModifyInstancesChargeTypeRequest, region: &str) -> ModifyInstancesChargeTypeResponseType;
+        /// 修改实例续费标识
+        fn modify_instances_renew_flag(&self, request: &ModifyInstancesRenewFlagRequest, region: &str) -> ModifyInstancesRenewFlagResponseType;
+        /// 修改实例的硬盘介质
+        fn modify_instance_disk_type(&self, request: &ModifyInstanceDiskTypeRequest, region: &str) -> ModifyInstanceDiskTypeResponseType;
+        /// 进入救援模式
+        fn enter_rescue_mode(&self, request: &EnterRescueModeRequest, region: &str) -> EnterRescueModeResponseType;
+        /// 退出救援模式
+        fn exit_rescue_mode(&self, request: &ExitRescueModeRequest, region: &str) -> ExitRescueModeResponseType;
+        /// 获取可用区的机型配置信息
+        fn describe_zone_instance_config_infos(&self, request: &DescribeZoneInstanceConfigInfosRequest, region: &str) -> DescribeZoneInstanceConfigInfosResponseType;
+        /// 批量获取指定实例属性
+        fn describe_instances_attribute(&self, request: &DescribeInstancesAttributeRequest, region: &str) -> DescribeInstancesAttributeResponseType;
+    }
+}
+
+impl<'a> InstanceQueryService<'a> {
+    /// 自动翻页查询实例列表，返回按页或逐条迭代的分页查询器
+    pub fn describe_instances_paginator(
+        &self,
+        request: DescribeInstancesRequest,
+        region: &str,
+    ) -> Paginator<'a, DescribeInstancesRequest, DescribeInstancesResponse> {
+        let inner = crate::services::cvm::instance_query::InstanceQueryService::new(&self.client.inner);
+        Paginator::new(self.client, inner.describe_instances_paginator(request, region))
+    }
+
+    /// 自动翻页查看实例状态列表，返回按页或逐条迭代的分页查询器
+    pub fn describe_instances_status_paginator(
+        &self,
+        request: DescribeInstancesStatusRequest,
+        region: &str,
+    ) -> Paginator<'a, DescribeInstancesStatusRequest, DescribeInstancesStatusResponse> {
+        let inner = crate::services::cvm::instance_query::InstanceQueryService::new(&self.client.inner);
+        Paginator::new(self.client, inner.describe_instances_status_paginator(request, region))
+    }
+}
+
+/// 阻塞的实例状态等待器
+///
+/// 轮询间隔、增长倍数、超时时间和宽限时间的含义与[`crate::services::cvm::instance_waiter::InstanceWaiter`]相同
+pub struct InstanceWaiter<'a> {
+    client: &'a TencentCloudClient,
+    inner: crate::services::cvm::instance_waiter::InstanceWaiter<'a>,
+}
+
+impl<'a> InstanceWaiter<'a> {
+    /// 创建新的实例状态等待器
+    pub fn new(client: &'a TencentCloudClient) -> Self {
+        Self {
+            client,
+            inner: crate::services::cvm::instance_waiter::InstanceWaiter::new(&client.inner),
+        }
+    }
+
+    /// 设置首次轮询间隔，默认为3秒
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.inner = self.inner.poll_interval(poll_interval);
+        self
+    }
+
+    /// 设置最大轮询间隔，默认为15秒
+    pub fn max_poll_interval(mut self, max_poll_interval: Duration) -> Self {
+        self.inner = self.inner.max_poll_interval(max_poll_interval);
+        self
+    }
+
+    /// 设置轮询间隔的增长倍数，默认为1.5，设置为1.0时按固定间隔轮询
+    pub fn backoff(mut self, backoff: f64) -> Self {
+        self.inner = self.inner.backoff(backoff);
+        self
+    }
+
+    /// 设置等待超时时间，默认为10分钟
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.inner = self.inner.timeout(timeout);
+        self
+    }
+
+    /// 设置查询不到实例时的宽限时间，默认为30秒
+    pub fn missing_grace_period(mut self, missing_grace_period: Duration) -> Self {
+        self.inner = self.inner.missing_grace_period(missing_grace_period);
+        self
+    }
+
+    /// 等待所有实例进入运行中（RUNNING）状态
+    pub fn wait_until_running(&self, instance_ids: &[String], region: &str) -> Result<Vec<InstanceStatus>> {
+        self.client.block_on(self.inner.wait_until_running(instance_ids, region))
+    }
+
+    /// 等待所有实例进入关机（STOPPED）状态
+    pub fn wait_until_stopped(&self, instance_ids: &[String], region: &str) -> Result<Vec<InstanceStatus>> {
+        self.client.block_on(self.inner.wait_until_stopped(instance_ids, region))
+    }
+
+    /// 等待所有实例退还完成，即查询不到这些实例
+    pub fn wait_until_terminated(&self, instance_ids: &[String], region: &str) -> Result<()> {
+        self.client.block_on(self.inner.wait_until_terminated(instance_ids, region))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ClientConfig;
+    use crate::credential::StaticCredentialProvider;
+    use crate::services::cvm::instance::InstanceState;
+    use crate::transport::{MockTransport, TransportResponse};
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_blocking_paginator_and_waiter() {
+        let mock = MockTransport::new();
+        mock.when("DescribeInstancesStatus").respond_with(|request| {
+            let params: Value = serde_json::from_str(&request.payload).unwrap();
+            let body = match params.get("InstanceIds") {
+                Some(_) => json!({"TotalCount": 1, "InstanceStatusSet": [{"InstanceId": "ins-1", "InstanceState": "RUNNING"}]}),
+                None => {
+                    let offset = params["Offset"].as_i64().unwrap();
+                    let set: Vec<Value> = (offset..(offset + 2).min(3))
+                        .map(|i| json!({"InstanceId": format!("ins-{}", i), "InstanceState": "STOPPED"}))
+                        .collect();
+                    json!({"TotalCount": 3, "InstanceStatusSet": set})
+                }
+            };
+            let mut body = body;
+            body["RequestId"] = json!("req-1");
+            Ok(TransportResponse::new(200, json!({"Response": body}).to_string()))
+        });
+
+        let client = TencentCloudClient::with_transport(
+            StaticCredentialProvider::new("test_id", "test_key"),
+            ClientConfig::default(),
+            mock.clone(),
+        );
+        let ids: Vec<String> = InstanceQueryService::new(&client)
+            .describe_instances_status_paginator(DescribeInstancesStatusRequest::default(), "ap-guangzhou")
+            .page_size(2)
+            .items()
+            .map(|status| status.map(|status| status.InstanceId))
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(ids, ["ins-0", "ins-1", "ins-2"]);
+
+        let statuses = InstanceWaiter::new(&client)
+            .poll_interval(Duration::from_millis(1))
+            .wait_until_running(&["ins-1".to_string()], "ap-guangzhou")
+            .unwrap();
+        assert_eq!(statuses[0].InstanceState, InstanceState::Running);
+        assert_eq!(mock.requests_for("DescribeInstancesStatus").len(), 3);
+    }
+}
@@ -0,0 +1,286 @@
+//! 阻塞（同步）客户端
+//!
+//! 启用`blocking`特性后可用。客户端内部持有一个单线程tokio运行时，
+//! 各服务的方法在该运行时上执行对应的异步方法并等待结果，适合同步的运维脚本和构建脚本。
+//!
+//! 不能在异步上下文（如`#[tokio::main]`）中创建、调用或释放阻塞客户端，否则tokio会panic。
+//!
+//! ```no_run
+//! use tencent_cloud_sdk::blocking::{RegionService, TencentCloudClient};
+//!
+//! let client = TencentCloudClient::from_default_chain();
+//! let regions = RegionService::new(&client).describe_regions()?;
+//! println!("{}", regions.Response.RegionSet.len());
+//! # Ok::<(), tencent_cloud_sdk::Error>(())
+//! ```
+
+#[cfg(feature = "cvm")]
+pub mod cvm;
+
+use futures::stream::{BoxStream, Stream, StreamExt};
+use futures::Future;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use tokio::runtime::{Builder, Runtime};
+
+use crate::action::Action;
+use crate::config::ClientConfig;
+use crate::credential::CredentialProvider;
+use crate::error::{Error, Result};
+use crate::paginator::{PagedRequest, PagedResponse};
+use crate::services::common::ApiResponse;
+#[cfg(feature = "region")]
+use crate::services::region::{DescribeRegionsResponse, DescribeZonesResponse};
+#[cfg(feature = "sts")]
+use crate::services::sts::{AssumeRoleRequest, AssumeRoleResponseType, GetFederationTokenRequest, GetFederationTokenResponseType};
+use crate::transport::Transport;
+
+#[cfg(feature = "cvm")]
+pub use cvm::{
+    InstanceConfigService, InstanceOperationService, InstancePriceService, InstanceQueryService, InstanceService,
+    InstanceWaiter,
+};
+
+/// 阻塞的腾讯云API客户端
+///
+/// 包装异步的[`crate::TencentCloudClient`]和执行请求的单线程tokio运行时
+pub struct TencentCloudClient {
+    inner: crate::client::TencentCloudClient,
+    runtime: Runtime,
+}
+
+impl TencentCloudClient {
+    /// 使用指定的凭证提供者创建新的阻塞客户端
+    ///
+    /// 创建tokio运行时失败时panic
+    pub fn new<P: CredentialProvider + 'static>(credential_provider: P) -> Self {
+        Self::from_async(crate::client::TencentCloudClient::new(credential_provider)).expect("创建tokio运行时失败")
+    }
+
+    /// 使用指定的凭证提供者和客户端配置创建新的阻塞客户端
+    ///
+    /// 代理地址无效、HTTP客户端或tokio运行时创建失败时返回错误
+    pub fn with_config<P: CredentialProvider + 'static>(credential_provider: P, config: ClientConfig) -> Result<Self> {
+        Self::from_async(crate::client::TencentCloudClient::with_config(credential_provider, config)?)
+    }
+
+    /// 使用指定的凭证提供者、客户端配置和传输层创建新的阻塞客户端
+    ///
+    /// 创建tokio运行时失败时panic
+    pub fn with_transport<P, T>(credential_provider: P, config: ClientConfig, transport: T) -> Self
+    where
+        P: CredentialProvider + 'static,
+        T: Transport + 'static,
+    {
+        Self::from_async(crate::client::TencentCloudClient::with_transport(credential_provider, config, transport))
+            .expect("创建tokio运行时失败")
+    }
+
+    /// 使用默认凭证提供者链创建新的阻塞客户端
+    ///
+    /// 依次尝试环境变量、`~/.tencentcloud/credentials`配置文件和CVM实例角色，创建tokio运行时失败时panic
+    pub fn from_default_chain() -> Self {
+        Self::from_async(crate::client::TencentCloudClient::from_default_chain()).expect("创建tokio运行时失败")
+    }
+
+    /// 把已有的异步客户端包装为阻塞客户端
+    pub fn from_async(client: crate::client::TencentCloudClient) -> Result<Self> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| Error::Other(format!("创建tokio运行时失败: {}", e)))?;
+        Ok(Self { inner: client, runtime })
+    }
+
+    /// 获取客户端配置
+    pub fn config(&self) -> &ClientConfig {
+        self.inner.config()
+    }
+
+    /// 获取内部的异步客户端
+    pub fn get_async_client(&self) -> &crate::client::TencentCloudClient {
+        &self.inner
+    }
+
+    /// 发送实现了`Action`的请求并等待响应
+    pub fn execute<A: Action>(&self, request: &A, region: Option<&str>) -> Result<ApiResponse<A::Response>> {
+        self.block_on(self.inner.execute(request, region))
+    }
+
+    /// 发送未封装的接口请求并等待响应，返回完整的`Response`对象（包含`RequestId`）
+    pub fn call_raw(&self, service: &str, version: &str, action: &str, region: Option<&str>, params: Value) -> Result<Value> {
+        self.block_on(self.inner.call_raw(service, version, action, region, params))
+    }
+
+    /// 发送请求到腾讯云API并等待响应
+    pub fn request<T, R>(&self, action: &str, params: &T, service: &str, version: &str, region: Option<&str>) -> Result<R>
+    where
+        T: Serialize,
+        R: DeserializeOwned,
+    {
+        self.block_on(self.inner.request(action, params, service, version, region))
+    }
+
+    /// 在内部运行时上执行异步任务并等待结果
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+}
+
+/// 生成包装异步服务的阻塞服务
+///
+/// 每个方法在客户端的运行时上调用异步服务的同名方法
+#[allow(unused_macros)]
+macro_rules! blocking_service {
+    (
+        $(#[$meta:meta])*
+        $name:ident => $inner:ty {
+            $(
+                $(#[$method_meta:meta])*
+                fn $method:ident(&self $(, $arg:ident: $arg_ty:ty)*) -> $ret:ty;
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        pub struct $name<'a> {
+            client: &'a $crate::blocking::TencentCloudClient,
+        }
+
+        impl<'a> $name<'a> {
+            /// 创建新的阻塞服务
+            pub fn new(client: &'a $crate::blocking::TencentCloudClient) -> Self {
+                Self { client }
+            }
+
+            $(
+                $(#[$method_meta])*
+                pub fn $method(&self $(, $arg: $arg_ty)*) -> $crate::error::Result<$ret> {
+                    self.client.block_on(<$inner>::new(&self.client.inner).$method($($arg),*))
+                }
+            )*
+        }
+    };
+}
+
+#[allow(unused_imports)]
+pub(crate) use blocking_service;
+
+#[cfg(feature = "region")]
+blocking_service! {
+    /// 阻塞的地域服务
+    RegionService => crate::services::region::RegionService {
+        /// 查询地域列表
+        fn describe_regions(&self) -> DescribeRegionsResponse;
+        /// 查询指定地域的可用区列表
+        fn describe_zones(&self, region: &str) -> DescribeZonesResponse;
+    }
+}
+
+#[cfg(feature = "sts")]
+blocking_service! {
+    /// 阻塞的安全凭证服务
+    StsService => crate::services::sts::StsService {
+        /// 申请扮演角色
+        fn assume_role(&self, request: &AssumeRoleRequest, region: &str) -> AssumeRoleResponseType;
+        /// 获取联合身份临时访问凭证
+        fn get_federation_token(&self, request: &GetFederationTokenRequest, region: &str) -> GetFederationTokenResponseType;
+    }
+}
+
+/// 阻塞的分页查询器
+///
+/// 包装异步的[`crate::paginator::Paginator`]，按页或逐条返回迭代器
+pub struct Paginator<'a, Req, Resp> {
+    client: &'a TencentCloudClient,
+    inner: crate::paginator::Paginator<'a, Req, Resp>,
+}
+
+impl<'a, Req, Resp> Paginator<'a, Req, Resp>
+where
+    Req: PagedRequest + Send + Sync + 'a,
+    Resp: PagedResponse + Send + 'a,
+    Resp::Item: Send + 'a,
+{
+    /// 使用阻塞客户端包装异步的分页查询器
+    pub fn new(client: &'a TencentCloudClient, inner: crate::paginator::Paginator<'a, Req, Resp>) -> Self {
+        Self { client, inner }
+    }
+
+    /// 设置每页数量，取值范围为1到100
+    pub fn page_size(mut self, page_size: i32) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// 设置同时请求的页数，默认为1，即逐页请求
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.inner = self.inner.concurrency(concurrency);
+        self
+    }
+
+    /// 按页返回查询结果
+    pub fn pages(self) -> BlockingIter<'a, Resp> {
+        BlockingIter::new(self.client, self.inner.pages())
+    }
+
+    /// 逐条返回查询结果
+    pub fn items(self) -> BlockingIter<'a, Resp::Item> {
+        BlockingIter::new(self.client, self.inner.items())
+    }
+}
+
+/// 在客户端运行时上逐个等待异步流结果的迭代器
+pub struct BlockingIter<'a, T> {
+    client: &'a TencentCloudClient,
+    stream: BoxStream<'a, Result<T>>,
+}
+
+impl<'a, T> BlockingIter<'a, T> {
+    fn new(client: &'a TencentCloudClient, stream: impl Stream<Item = Result<T>> + Send + 'a) -> Self {
+        Self {
+            client,
+            stream: stream.boxed(),
+        }
+    }
+}
+
+impl<T> Iterator for BlockingIter<'_, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.client.block_on(self.stream.next())
+    }
+}
+
+#[cfg(all(test, feature = "region"))]
+mod tests {
+    use super::*;
+    use crate::credential::StaticCredentialProvider;
+    use crate::transport::MockTransport;
+    use serde_json::json;
+
+    #[test]
+    fn test_blocking_region_service() {
+        let mock = MockTransport::new();
+        mock.when("DescribeRegions").respond_json(json!({
+            "Response": {
+                "RegionSet": [{"Region": "ap-guangzhou", "RegionName": "华南地区(广州)", "RegionState": "AVAILABLE"}],
+                "RequestId": "req-1"
+            }
+        }));
+        mock.when("DescribeZones").respond_error("InvalidParameterValue", "地域不存在");
+
+        let client = TencentCloudClient::with_transport(
+            StaticCredentialProvider::new("test_id", "test_key"),
+            ClientConfig::default(),
+            mock.clone(),
+        );
+        let service = RegionService::new(&client);
+        let regions = service.describe_regions().unwrap();
+        assert_eq!(regions.Response.RegionSet[0].Region, "ap-guangzhou");
+
+        let err = service.describe_zones("ap-nowhere").unwrap_err();
+        assert!(matches!(err, Error::ApiError { ref code, .. } if code.to_string() == "InvalidParameterValue"));
+        assert_eq!(mock.requests_for("DescribeZones")[0].region.as_deref(), Some("ap-nowhere"));
+    }
+}
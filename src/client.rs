@@ -1,35 +1,111 @@
 use serde::{de::DeserializeOwned, Serialize};
-use serde_json::json;
+use serde_json::{json, Value};
+use std::sync::Arc;
 
-use crate::error::Result;
-use crate::utils::http::HttpClient;
-use crate::utils::signature::SignatureBuilder;
+use crate::action::Action;
+use crate::config::ClientConfig;
+use crate::credential::{CredentialChain, CredentialProvider, RefreshingCredentialProvider};
+use crate::error::{Error, Result};
+use crate::services::common::ApiResponse;
+use crate::transport::{Transport, TransportRequest};
+use crate::utils::http::{parse_response, HttpClient};
+use crate::utils::signature::{canonical_query_string, RequestStyle, SignatureBuilder};
 
 /// 腾讯云API客户端
 pub struct TencentCloudClient {
-    secret_id: String,
-    secret_key: String,
-    http_client: HttpClient,
+    credential_provider: Arc<dyn CredentialProvider>,
+    transport: Arc<dyn Transport>,
+    config: ClientConfig,
 }
 
 impl TencentCloudClient {
-    /// 创建新的腾讯云API客户端
-    pub fn new(secret_id: String, secret_key: String) -> Self {
+    /// 使用指定的凭证提供者创建新的腾讯云API客户端
+    ///
+    /// 凭证会被缓存，临时凭证在过期前会自动刷新
+    pub fn new<P: CredentialProvider + 'static>(credential_provider: P) -> Self {
         Self {
-            secret_id,
-            secret_key,
-            http_client: HttpClient::new(),
+            credential_provider: Arc::new(RefreshingCredentialProvider::new(credential_provider)),
+            transport: Arc::new(HttpClient::new()),
+            config: ClientConfig::default(),
         }
     }
+
+    /// 使用指定的凭证提供者和客户端配置创建新的腾讯云API客户端
+    ///
+    /// 代理地址无效或HTTP客户端创建失败时返回错误
+    pub fn with_config<P: CredentialProvider + 'static>(credential_provider: P, config: ClientConfig) -> Result<Self> {
+        Ok(Self {
+            credential_provider: Arc::new(RefreshingCredentialProvider::new(credential_provider)),
+            transport: Arc::new(HttpClient::with_config(&config)?),
+            config,
+        })
+    }
+
+    /// 使用指定的凭证提供者、客户端配置和传输层创建新的腾讯云API客户端
+    ///
+    /// 配置中的代理和超时时间只对默认的`HttpClient`生效，测试时可以传入`MockTransport`
+    pub fn with_transport<P, T>(credential_provider: P, config: ClientConfig, transport: T) -> Self
+    where
+        P: CredentialProvider + 'static,
+        T: Transport + 'static,
+    {
+        Self {
+            credential_provider: Arc::new(RefreshingCredentialProvider::new(credential_provider)),
+            transport: Arc::new(transport),
+            config,
+        }
+    }
+
+    /// 获取客户端配置
+    pub fn config(&self) -> &ClientConfig {
+        &self.config
+    }
+
+    /// 使用默认凭证提供者链创建新的腾讯云API客户端
+    ///
+    /// 依次尝试环境变量、`~/.tencentcloud/credentials`配置文件和CVM实例角色
+    pub fn from_default_chain() -> Self {
+        Self::new(CredentialChain::default())
+    }
     
+    /// 发送实现了`Action`的请求，接口名称、服务和版本取自请求类型
+    ///
+    /// 不需要地域的接口（如DescribeRegions）传入`None`
+    pub async fn execute<A: Action>(&self, request: &A, region: Option<&str>) -> Result<ApiResponse<A::Response>> {
+        self.request(A::NAME, request, A::SERVICE, A::VERSION, region).await
+    }
+
+    /// 发送未封装的接口请求，返回完整的`Response`对象（包含`RequestId`）
+    ///
+    /// 参数必须是JSON对象，`null`视为空对象。API错误仍会返回`Error::ApiError`
+    ///
+    /// ```no_run
+    /// # async fn run(client: &tencent_cloud_sdk::TencentCloudClient) -> tencent_cloud_sdk::error::Result<()> {
+    /// let response = client
+    ///     .call_raw("cvm", "2017-03-12", "DescribeKeyPairs", Some("ap-guangzhou"), serde_json::json!({"Limit": 10}))
+    ///     .await?;
+    /// println!("{} {}", response["TotalCount"], response["RequestId"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn call_raw(&self, service: &str, version: &str, action: &str, region: Option<&str>, params: Value) -> Result<Value> {
+        let params = match params {
+            Value::Null => json!({}),
+            Value::Object(_) => params,
+            _ => return Err(Error::ParameterError(format!("{}的请求参数必须是JSON对象", action))),
+        };
+        let response: ApiResponse<Value> = self.request(action, &params, service, version, region).await?;
+        Ok(response.Response)
+    }
+
     /// 发送请求到腾讯云API
     pub async fn request<T, R>(&self, action: &str, params: &T, service: &str, version: &str, region: Option<&str>) -> Result<R>
     where
         T: Serialize,
         R: DeserializeOwned,
     {
-        // 获取服务域名
-        let host = format!("{}.tencentcloudapi.com", service);
+        // 获取服务协议和域名
+        let (scheme, host) = self.config.resolve_endpoint(service, region);
         
         // 将参数序列化为JSON
         let mut json_params = serde_json::to_value(params).unwrap_or(json!({}));
@@ -39,41 +115,383 @@ impl TencentCloudClient {
             json_params = json!({});
         }
         
-        // 序列化为字符串
-        let payload = json_params.to_string();
-        
-        // 构建签名
-        let mut builder = SignatureBuilder::new(
-            self.secret_id.clone(),
-            self.secret_key.clone(),
-            service.to_string(),
-            host,
-        )
-        .action(action.to_string())
-        .version(version.to_string())
-        .payload(payload);
+        // 按服务的请求方式编码为JSON字符串或查询字符串
+        let style = self.config.get_request_style(service);
+        let payload = match style {
+            RequestStyle::Json => json_params.to_string(),
+            RequestStyle::Form | RequestStyle::Get => {
+                let mut params = Vec::new();
+                flatten_params("", &json_params, &mut params);
+                canonical_query_string(&params)
+            }
+        };
         
-        // 如果提供了区域，则设置区域
-        if let Some(region_value) = region {
-            builder = builder.region(region_value.to_string());
+        // 可能已执行的失败（连接错误、超时、5xx响应、InternalError）只对幂等接口或携带ClientToken的请求重试
+        let has_client_token = json_params
+            .get("ClientToken")
+            .and_then(Value::as_str)
+            .is_some_and(|token| !token.is_empty());
+        let idempotent = has_client_token || self.config.get_retry_policy().is_idempotent(action);
+
+        let future = self.send_with_retry(action, payload, style, service, version, region, scheme, host, idempotent);
+        #[cfg(feature = "tracing")]
+        let future = tracing::Instrument::instrument(
+            future,
+            tracing::info_span!("tencentcloud.request", service, action, version, region = region.unwrap_or_default()),
+        );
+        future.await
+    }
+
+    /// 签名并发送请求，可重试的错误按重试策略重新签名后重试
+    ///
+    /// `idempotent`为`false`时只重试RequestLimitExceeded等表示请求未执行的错误
+    #[allow(clippy::too_many_arguments)]
+    async fn send_with_retry<R>(
+        &self,
+        action: &str,
+        payload: String,
+        style: RequestStyle,
+        service: &str,
+        version: &str,
+        region: Option<&str>,
+        scheme: String,
+        host: String,
+        idempotent: bool,
+    ) -> Result<R>
+    where
+        R: DeserializeOwned,
+    {
+        let retry_policy = self.config.get_retry_policy();
+        let mut attempt = 0;
+        loop {
+            // 等待限流器发放令牌
+            if let Some(rate_limiter) = self.config.get_rate_limiter() {
+                rate_limiter.acquire(service, action).await;
+            }
+
+            // 每次尝试都重新获取凭证并重新签名，保证时间戳有效
+            let credential = self.credential_provider.credential().await?;
+            let mut builder = SignatureBuilder::new(
+                credential.secret_id,
+                credential.secret_key,
+                service.to_string(),
+                host.clone(),
+            )
+            .action(action.to_string())
+            .version(version.to_string())
+            .payload(payload.clone())
+            .style(style)
+            .scheme(scheme.clone())
+            .token(credential.token);
+            
+            // 如果提供了区域，则设置区域
+            if let Some(region_value) = region {
+                builder = builder.region(region_value.to_string());
+            }
+
+            // 测试时使用固定的时间戳
+            if let Some(timestamp) = self.config.get_signing_timestamp() {
+                builder = builder.timestamp(timestamp);
+            }
+            
+            // 生成签名信息
+            let signature_info = builder.build();
+            
+            // 发送请求并解析响应，可重试的错误按退避时间等待后重试
+            let request = TransportRequest::new(service, action, version, region, signature_info);
+            let future = async { parse_response(self.transport.send(request).await?) };
+            #[cfg(feature = "tracing")]
+            let future = tracing::Instrument::instrument(future, tracing::info_span!("attempt", attempt));
+            match future.await {
+                Err(err) if attempt < retry_policy.get_max_retries() && retry_policy.should_retry(&err, idempotent) => {
+                    let delay = retry_policy.delay(attempt);
+                    #[cfg(feature = "tracing")]
+                    tracing::info!(attempt, delay_ms = delay.as_millis() as u64, error = %err, "请求失败，等待后重试");
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
         }
-        
-        // 生成签名信息
-        let signature_info = builder.build();
-        
-        // 发送请求并解析响应
-        self.http_client.send_request(signature_info).await
+    }
+}
+
+/// 把JSON参数展开为键值对，嵌套对象用`.`连接，数组元素使用从0开始的下标，如`Filters.0.Values.0`
+///
+/// 空值会被忽略，布尔值展开为`true`或`false`
+fn flatten_params(prefix: &str, value: &Value, params: &mut Vec<(String, String)>) {
+    let key = |name: &str| if prefix.is_empty() { name.to_string() } else { format!("{}.{}", prefix, name) };
+    match value {
+        Value::Null => {}
+        Value::Object(map) => map.iter().for_each(|(name, value)| flatten_params(&key(name), value, params)),
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .for_each(|(index, value)| flatten_params(&key(&index.to_string()), value, params)),
+        Value::String(text) => params.push((prefix.to_string(), text.clone())),
+        Value::Bool(_) | Value::Number(_) => params.push((prefix.to_string(), value.to_string())),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::credential::StaticCredentialProvider;
+    use crate::retry::RetryPolicy;
+    use crate::transport::MockTransport;
+    use crate::utils::test_server::TestServer;
     
+    #[tokio::test]
+    async fn test_create_client() {
+        let client = TencentCloudClient::new(StaticCredentialProvider::new("test_id", "test_key"));
+        let credential = client.credential_provider.credential().await.unwrap();
+        assert_eq!(credential.secret_id, "test_id");
+        assert_eq!(credential.secret_key, "test_key");
+    }
+
+    #[tokio::test]
+    async fn test_request_with_mock_transport() {
+        let mock = MockTransport::new();
+        mock.when("DescribeRegions")
+            .service("cvm")
+            .respond_json(serde_json::json!({"Response": {"RequestId": "req-1"}}));
+        mock.when("DescribeZones").respond_error("UnauthorizedOperation", "denied");
+
+        let client = TencentCloudClient::with_transport(
+            StaticCredentialProvider::new("test_id", "test_key"),
+            ClientConfig::default(),
+            mock.clone(),
+        );
+        let response: serde_json::Value = client
+            .request("DescribeRegions", &(), "cvm", "2017-03-12", Some("ap-guangzhou"))
+            .await
+            .unwrap();
+        assert_eq!(response["Response"]["RequestId"], "req-1");
+        let result: Result<serde_json::Value> = client.request("DescribeZones", &(), "cvm", "2017-03-12", None).await;
+        assert_eq!(result.unwrap_err().request_id(), Some(crate::transport::mock::MOCK_REQUEST_ID));
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].region.as_deref(), Some("ap-guangzhou"));
+        assert!(requests[0].header("authorization").unwrap().starts_with("TC3-HMAC-SHA256"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_custom_action() {
+        #[derive(Serialize)]
+        struct DescribeKeyPairsRequest {
+            Limit: i32,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct DescribeKeyPairsResponse {
+            TotalCount: i32,
+        }
+
+        impl Action for DescribeKeyPairsRequest {
+            const NAME: &'static str = "DescribeKeyPairs";
+            const SERVICE: &'static str = "cvm";
+            const VERSION: &'static str = "2017-03-12";
+            type Response = DescribeKeyPairsResponse;
+        }
+
+        let mock = MockTransport::new();
+        mock.when("DescribeKeyPairs")
+            .service("cvm")
+            .respond_json(json!({"Response": {"TotalCount": 3, "RequestId": "req-1"}}));
+
+        let client = TencentCloudClient::with_transport(
+            StaticCredentialProvider::new("test_id", "test_key"),
+            ClientConfig::default(),
+            mock.clone(),
+        );
+        let response = client.execute(&DescribeKeyPairsRequest { Limit: 10 }, Some("ap-guangzhou")).await.unwrap();
+        assert_eq!(response.Response.TotalCount, 3);
+
+        let request = &mock.requests()[0];
+        assert_eq!(request.version, "2017-03-12");
+        assert_eq!(request.header("x-tc-version"), Some("2017-03-12"));
+        assert_eq!(request.payload, r#"{"Limit":10}"#);
+    }
+
+    #[tokio::test]
+    async fn test_call_raw() {
+        let mock = MockTransport::new();
+        mock.when("DescribeKeyPairs")
+            .respond_json(json!({"Response": {"TotalCount": 0, "KeyPairSet": [], "RequestId": "req-1"}}));
+        mock.when("DeleteKeyPairs").respond_error("InvalidKeyPairId.NotFound", "密钥对不存在");
+
+        let client = TencentCloudClient::with_transport(
+            StaticCredentialProvider::new("test_id", "test_key"),
+            ClientConfig::default(),
+            mock.clone(),
+        );
+        let response = client
+            .call_raw("cvm", "2017-03-12", "DescribeKeyPairs", Some("ap-guangzhou"), json!({"Limit": 10}))
+            .await
+            .unwrap();
+        assert_eq!(response, json!({"TotalCount": 0, "KeyPairSet": [], "RequestId": "req-1"}));
+
+        let err = client
+            .call_raw("cvm", "2017-03-12", "DeleteKeyPairs", Some("ap-guangzhou"), Value::Null)
+            .await
+            .unwrap_err();
+        assert_eq!(err.request_id(), Some(crate::transport::mock::MOCK_REQUEST_ID));
+        let err = client.call_raw("cvm", "2017-03-12", "DescribeKeyPairs", None, json!([1])).await.unwrap_err();
+        assert!(matches!(err, Error::ParameterError(_)));
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].payload, r#"{"Limit":10}"#);
+        assert_eq!(requests[1].payload, "{}");
+    }
+
+    #[tokio::test]
+    async fn test_request_uses_endpoint_override() {
+        let server = TestServer::start(|_| (200, r#"{"Response":{"RequestId":"req-1"}}"#.to_string())).await;
+
+        let config = ClientConfig::new().endpoint("cvm", server.url.clone());
+        let client = TencentCloudClient::with_config(StaticCredentialProvider::new("test_id", "test_key"), config).unwrap();
+        let response: serde_json::Value = client
+            .request("DescribeRegions", &(), "cvm", "2017-03-12", None)
+            .await
+            .unwrap();
+        assert_eq!(response["Response"]["RequestId"], "req-1");
+
+        let requests = server.requests();
+        assert_eq!(requests[0].header("host"), Some(server.host()));
+        assert_eq!(requests[0].header("x-tc-action"), Some("DescribeRegions"));
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].body, "{}");
+    }
+
     #[test]
-    fn test_create_client() {
-        let client = TencentCloudClient::new("test_id".to_string(), "test_key".to_string());
-        assert_eq!(client.secret_id, "test_id");
-        assert_eq!(client.secret_key, "test_key");
+    fn test_flatten_params() {
+        let value = json!({
+            "Filters": [{"Name": "zone", "Values": ["ap-guangzhou-3", "ap-guangzhou-6"]}],
+            "Limit": 20,
+            "DryRun": false,
+            "InstanceIds": null,
+        });
+        let mut params = Vec::new();
+        flatten_params("", &value, &mut params);
+        assert_eq!(
+            canonical_query_string(&params),
+            "DryRun=false&Filters.0.Name=zone&Filters.0.Values.0=ap-guangzhou-3&Filters.0.Values.1=ap-guangzhou-6&Limit=20"
+        );
     }
-} 
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_get_and_form_requests() {
+        let server = TestServer::start(|_| (200, r#"{"Response":{"RequestId":"req-1"}}"#.to_string())).await;
+        let params = json!({"Limit": 10, "InstanceName": "测试 实例"});
+
+        for style in [RequestStyle::Get, RequestStyle::Form] {
+            let config = ClientConfig::new()
+                .endpoint("cvm", server.url.clone())
+                .request_style("cvm", style)
+                .signing_timestamp(1700000000);
+            let client = TencentCloudClient::with_config(StaticCredentialProvider::new("test_id", "test_key"), config).unwrap();
+            let _: Value = client
+                .request("DescribeInstances", &params, "cvm", "2017-03-12", Some("ap-guangzhou"))
+                .await
+                .unwrap();
+        }
+
+        let query = "InstanceName=%E6%B5%8B%E8%AF%95%20%E5%AE%9E%E4%BE%8B&Limit=10";
+        let requests = server.requests();
+        let verify = |style: RequestStyle, request: &crate::utils::test_server::RecordedRequest| {
+            SignatureBuilder::new("test_id".to_string(), "test_key".to_string(), "cvm".to_string(), server.host().to_string())
+                .style(style)
+                .timestamp(1700000000)
+                .payload(query.to_string())
+                .verify(request.header("authorization").unwrap())
+        };
+
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, format!("/?{}", query));
+        assert_eq!(requests[0].body, "");
+        assert!(verify(RequestStyle::Get, &requests[0]));
+
+        assert_eq!(requests[1].method, "POST");
+        assert_eq!(requests[1].header("content-type"), Some("application/x-www-form-urlencoded"));
+        assert_eq!(requests[1].body, query);
+        assert!(verify(RequestStyle::Form, &requests[1]));
+    }
+
+    #[tokio::test]
+    async fn test_request_retries_throttled_response() {
+        let calls = std::sync::atomic::AtomicUsize::new(0);
+        let server = TestServer::start(move |_| {
+            if calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) < 2 {
+                (200, r#"{"Response":{"Error":{"Code":"RequestLimitExceeded","Message":"slow down"},"RequestId":"req-1"}}"#.to_string())
+            } else {
+                (200, r#"{"Response":{"RequestId":"req-2"}}"#.to_string())
+            }
+        })
+        .await;
+
+        let config = ClientConfig::new()
+            .endpoint("cvm", server.url.clone())
+            .retry_policy(RetryPolicy::new().base_delay(std::time::Duration::from_millis(1)));
+        let client = TencentCloudClient::with_config(StaticCredentialProvider::new("test_id", "test_key"), config).unwrap();
+        let response: serde_json::Value = client
+            .request("DescribeRegions", &(), "cvm", "2017-03-12", None)
+            .await
+            .unwrap();
+        assert_eq!(response["Response"]["RequestId"], "req-2");
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_request_does_not_retry_other_errors() {
+        let server = TestServer::start(|_| {
+            (200, r#"{"Response":{"Error":{"Code":"AuthFailure.SignatureFailure","Message":"bad"},"RequestId":"req-1"}}"#.to_string())
+        })
+        .await;
+
+        let config = ClientConfig::new()
+            .endpoint("cvm", server.url.clone())
+            .retry_policy(RetryPolicy::new().base_delay(std::time::Duration::from_millis(1)));
+        let client = TencentCloudClient::with_config(StaticCredentialProvider::new("test_id", "test_key"), config).unwrap();
+        let result: Result<serde_json::Value> = client.request("DescribeRegions", &(), "cvm", "2017-03-12", None).await;
+        assert!(result.is_err());
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_timeouts_retried_only_for_idempotent_requests() {
+        // 接受连接但不返回响应，使每次请求都超时
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let connections = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let accepted = connections.clone();
+        tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                accepted.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                tokio::spawn(async move {
+                    tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+                    drop(socket);
+                });
+            }
+        });
+
+        let config = ClientConfig::new()
+            .endpoint("cvm", url)
+            .timeout(std::time::Duration::from_millis(100))
+            .retry_policy(RetryPolicy::new().max_retries(2).base_delay(std::time::Duration::from_millis(1)));
+        let client = TencentCloudClient::with_config(StaticCredentialProvider::new("test_id", "test_key"), config).unwrap();
+        // 非幂等接口超时后不重试，携带ClientToken或幂等接口按策略重试
+        let cases = [
+            ("RunInstances", json!({}), 1),
+            ("RunInstances", json!({"ClientToken": "token-1"}), 3),
+            ("DescribeInstances", json!({}), 3),
+        ];
+        for (action, params, attempts) in cases {
+            let before = connections.load(std::sync::atomic::Ordering::SeqCst);
+            let result: Result<Value> = client.request(action, &params, "cvm", "2017-03-12", None).await;
+            assert!(matches!(result, Err(Error::RequestError(ref err)) if err.is_timeout()));
+            assert_eq!(connections.load(std::sync::atomic::Ordering::SeqCst) - before, attempts, "{}", action);
+        }
+    }
+}
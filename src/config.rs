@@ -0,0 +1,232 @@
+//! 客户端配置
+//!
+//! 包含服务域名、协议、代理、超时时间、重试和限流等配置项
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::rate_limit::RateLimiter;
+use crate::retry::RetryPolicy;
+use crate::utils::signature::RequestStyle;
+
+/// 默认的服务根域名
+pub const DEFAULT_DOMAIN: &str = "tencentcloudapi.com";
+
+/// 内网访问的服务根域名
+pub const INTERNAL_DOMAIN: &str = "internal.tencentcloudapi.com";
+
+/// 默认的请求协议
+pub const DEFAULT_SCHEME: &str = "https";
+
+/// 默认的请求超时时间
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 腾讯云API客户端配置
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    /// 按服务覆盖的接入地址
+    endpoints: HashMap<String, String>,
+    /// 按服务指定的请求方式
+    request_styles: HashMap<String, RequestStyle>,
+    /// 请求协议
+    scheme: String,
+    /// 服务根域名
+    domain: String,
+    /// 是否使用地域接入域名
+    regional_endpoint: bool,
+    /// HTTP代理地址
+    proxy: Option<String>,
+    /// 请求超时时间
+    timeout: Duration,
+    /// 请求重试策略
+    retry_policy: RetryPolicy,
+    /// 客户端限流器
+    rate_limiter: Option<RateLimiter>,
+    /// 固定的签名时间戳
+    signing_timestamp: Option<u64>,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            endpoints: HashMap::new(),
+            request_styles: HashMap::new(),
+            scheme: DEFAULT_SCHEME.to_string(),
+            domain: DEFAULT_DOMAIN.to_string(),
+            regional_endpoint: false,
+            proxy: None,
+            timeout: DEFAULT_TIMEOUT,
+            retry_policy: RetryPolicy::none(),
+            rate_limiter: None,
+            signing_timestamp: None,
+        }
+    }
+}
+
+impl ClientConfig {
+    /// 创建默认配置
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 覆盖指定服务的接入地址
+    ///
+    /// 可以是域名（如`cvm.ap-guangzhou.tencentcloudapi.com`），
+    /// 也可以是带协议的地址（如`http://127.0.0.1:8080`），主要用于指向本地模拟服务
+    pub fn endpoint(mut self, service: impl Into<String>, endpoint: impl Into<String>) -> Self {
+        self.endpoints.insert(service.into(), endpoint.into());
+        self
+    }
+
+    /// 设置指定服务的请求方式，默认为JSON请求
+    ///
+    /// 部分旧接口只支持GET或`application/x-www-form-urlencoded`表单请求，
+    /// 这两种方式的参数会展开为`Filters.0.Name`形式并按RFC3986编码
+    pub fn request_style(mut self, service: impl Into<String>, style: RequestStyle) -> Self {
+        self.request_styles.insert(service.into(), style);
+        self
+    }
+
+    /// 设置请求协议，默认为`https`
+    pub fn scheme(mut self, scheme: impl Into<String>) -> Self {
+        self.scheme = scheme.into();
+        self
+    }
+
+    /// 设置服务根域名，默认为`tencentcloudapi.com`
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.domain = domain.into();
+        self
+    }
+
+    /// 使用内网接入域名`internal.tencentcloudapi.com`
+    pub fn internal(self) -> Self {
+        self.domain(INTERNAL_DOMAIN)
+    }
+
+    /// 是否使用地域接入域名，如`cvm.ap-guangzhou.tencentcloudapi.com`
+    pub fn regional_endpoint(mut self, regional_endpoint: bool) -> Self {
+        self.regional_endpoint = regional_endpoint;
+        self
+    }
+
+    /// 设置HTTP代理地址，如`http://127.0.0.1:7890`
+    pub fn proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.proxy = Some(proxy.into());
+        self
+    }
+
+    /// 设置请求超时时间，默认为30秒
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// 设置请求重试策略，默认不重试
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// 设置客户端限流器，默认不限流
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// 固定签名使用的时间戳（Unix时间戳，秒），默认使用当前时间
+    ///
+    /// 仅用于测试，如配合`CassetteTransport`回放时让请求头保持不变。
+    /// 真实请求的时间戳与服务器时间相差超过5分钟会签名失败
+    pub fn signing_timestamp(mut self, timestamp: u64) -> Self {
+        self.signing_timestamp = Some(timestamp);
+        self
+    }
+
+    /// 获取HTTP代理地址
+    pub fn get_proxy(&self) -> Option<&str> {
+        self.proxy.as_deref()
+    }
+
+    /// 获取请求超时时间
+    pub fn get_timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// 获取请求重试策略
+    pub fn get_retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// 获取客户端限流器
+    pub fn get_rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_ref()
+    }
+
+    /// 获取指定服务的请求方式
+    pub fn get_request_style(&self, service: &str) -> RequestStyle {
+        self.request_styles.get(service).copied().unwrap_or_default()
+    }
+
+    /// 获取固定的签名时间戳
+    pub fn get_signing_timestamp(&self) -> Option<u64> {
+        self.signing_timestamp
+    }
+
+    /// 解析服务的请求协议和域名
+    pub fn resolve_endpoint(&self, service: &str, region: Option<&str>) -> (String, String) {
+        if let Some(endpoint) = self.endpoints.get(service) {
+            return match endpoint.split_once("://") {
+                Some((scheme, host)) => (scheme.to_string(), host.trim_end_matches('/').to_string()),
+                None => (self.scheme.clone(), endpoint.clone()),
+            };
+        }
+
+        let host = match region {
+            Some(region) if self.regional_endpoint && !region.is_empty() => {
+                format!("{}.{}.{}", service, region, self.domain)
+            }
+            _ => format!("{}.{}", service, self.domain),
+        };
+        (self.scheme.clone(), host)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_endpoint() {
+        let config = ClientConfig::new();
+        assert_eq!(
+            config.resolve_endpoint("cvm", Some("ap-guangzhou")),
+            ("https".to_string(), "cvm.tencentcloudapi.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_regional_internal_endpoint() {
+        let config = ClientConfig::new().regional_endpoint(true).internal();
+        assert_eq!(
+            config.resolve_endpoint("cvm", Some("ap-guangzhou")).1,
+            "cvm.ap-guangzhou.internal.tencentcloudapi.com"
+        );
+        assert_eq!(config.resolve_endpoint("cvm", None).1, "cvm.internal.tencentcloudapi.com");
+    }
+
+    #[test]
+    fn test_endpoint_override() {
+        let config = ClientConfig::new()
+            .endpoint("cvm", "http://127.0.0.1:8080/")
+            .endpoint("sts", "sts.ap-beijing.tencentcloudapi.com");
+        assert_eq!(
+            config.resolve_endpoint("cvm", Some("ap-guangzhou")),
+            ("http".to_string(), "127.0.0.1:8080".to_string())
+        );
+        assert_eq!(
+            config.resolve_endpoint("sts", None),
+            ("https".to_string(), "sts.ap-beijing.tencentcloudapi.com".to_string())
+        );
+    }
+}
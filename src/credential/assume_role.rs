@@ -0,0 +1,221 @@
+//! STS扮演角色凭证提供者
+//!
+//! 使用基础凭证调用STS的AssumeRole接口，获取指定角色的临时凭证
+
+use async_trait::async_trait;
+
+use super::{Credential, CredentialProvider};
+use crate::client::TencentCloudClient;
+use crate::error::Result;
+use crate::services::sts::{AssumeRoleRequest, StsService};
+
+/// 默认的STS请求地域
+pub const DEFAULT_STS_REGION: &str = "ap-guangzhou";
+
+/// 默认的临时凭证有效期，单位：秒
+pub const DEFAULT_DURATION_SECONDS: u64 = 7200;
+
+/// STS扮演角色凭证提供者
+///
+/// 返回的临时凭证由客户端缓存，并在`ExpiredTime`之前自动重新扮演角色
+pub struct AssumeRoleCredentialProvider {
+    client: TencentCloudClient,
+    role_arn: String,
+    role_session_name: String,
+    region: String,
+    duration_seconds: u64,
+    policy: Option<String>,
+    external_id: Option<String>,
+}
+
+impl AssumeRoleCredentialProvider {
+    /// 创建新的扮演角色凭证提供者
+    ///
+    /// `base_provider`提供调用AssumeRole所需的基础凭证，STS请求使用默认的客户端配置。
+    /// 需要代理、接入地址、超时时间或重试策略时使用[`AssumeRoleCredentialProvider::with_client`]
+    pub fn new<P: CredentialProvider + 'static>(
+        base_provider: P,
+        role_arn: impl Into<String>,
+        role_session_name: impl Into<String>,
+    ) -> Self {
+        Self::with_client(TencentCloudClient::new(base_provider), role_arn, role_session_name)
+    }
+
+    /// 使用已有的客户端创建扮演角色凭证提供者
+    ///
+    /// `client`的凭证作为基础凭证，STS请求使用该客户端的配置和传输层
+    pub fn with_client(
+        client: TencentCloudClient,
+        role_arn: impl Into<String>,
+        role_session_name: impl Into<String>,
+    ) -> Self {
+        Self {
+            client,
+            role_arn: role_arn.into(),
+            role_session_name: role_session_name.into(),
+            region: DEFAULT_STS_REGION.to_string(),
+            duration_seconds: DEFAULT_DURATION_SECONDS,
+            policy: None,
+            external_id: None,
+        }
+    }
+
+    /// 设置STS请求地域
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        self.region = region.into();
+        self
+    }
+
+    /// 设置临时凭证有效期，单位：秒，最长43200秒
+    pub fn duration_seconds(mut self, duration_seconds: u64) -> Self {
+        self.duration_seconds = duration_seconds;
+        self
+    }
+
+    /// 设置限制临时凭证权限的策略（需经过URL编码）
+    pub fn policy(mut self, policy: impl Into<String>) -> Self {
+        self.policy = Some(policy.into());
+        self
+    }
+
+    /// 设置角色外部ID
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for AssumeRoleCredentialProvider {
+    async fn credential(&self) -> Result<Credential> {
+        let request = AssumeRoleRequest {
+            RoleArn: self.role_arn.clone(),
+            RoleSessionName: self.role_session_name.clone(),
+            DurationSeconds: Some(self.duration_seconds),
+            Policy: self.policy.clone(),
+            ExternalId: self.external_id.clone(),
+            Tags: None,
+            SourceIdentity: None,
+        };
+
+        let response = StsService::new(&self.client)
+            .assume_role(&request, &self.region)
+            .await?
+            .Response;
+
+        Ok(Credential::temporary(
+            response.Credentials.TmpSecretId,
+            response.Credentials.TmpSecretKey,
+            response.Credentials.Token,
+            response.ExpiredTime,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ClientConfig;
+    use crate::credential::StaticCredentialProvider;
+    use crate::transport::{MockTransport, TransportResponse};
+    use crate::utils::signature::get_timestamp;
+    use serde_json::json;
+
+    /// STS替身，每次扮演角色返回新的临时凭证，有效期为`lifetime`秒
+    fn sts_mock(lifetime: u64) -> MockTransport {
+        let mock = MockTransport::new();
+        let calls = std::sync::atomic::AtomicU64::new(0);
+        mock.when("AssumeRole").service("sts").respond_with(move |_| {
+            let n = calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let body = json!({
+                "Response": {
+                    "Credentials": {"Token": format!("token{}", n), "TmpSecretId": format!("AKIDtmp{}", n), "TmpSecretKey": "tmpkey"},
+                    "ExpiredTime": get_timestamp() + lifetime,
+                    "Expiration": "2030-01-01T00:00:00Z",
+                    "RequestId": format!("req-{}", n)
+                }
+            });
+            Ok(TransportResponse::new(200, body.to_string()))
+        });
+        mock
+    }
+
+    fn provider(sts: &MockTransport) -> AssumeRoleCredentialProvider {
+        let client = TencentCloudClient::with_transport(
+            StaticCredentialProvider::new("base_id", "base_key"),
+            ClientConfig::default(),
+            sts.clone(),
+        );
+        AssumeRoleCredentialProvider::with_client(client, "qcs::cam::uin/100:roleName/tenant", "session")
+            .region("ap-shanghai")
+            .duration_seconds(900)
+            .external_id("ext-1")
+    }
+
+    /// 使用扮演角色凭证的客户端，业务请求发送到`api`
+    fn tenant_client(sts: &MockTransport, api: &MockTransport) -> TencentCloudClient {
+        api.when("DescribeKeyPairs").respond_json(json!({"Response": {"RequestId": "req-api"}}));
+        TencentCloudClient::with_transport(provider(sts), ClientConfig::default(), api.clone())
+    }
+
+    async fn call(client: &TencentCloudClient) {
+        client.call_raw("cvm", "2017-03-12", "DescribeKeyPairs", Some("ap-guangzhou"), json!({})).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_assume_role_fetches_credential() {
+        let sts = sts_mock(3600);
+        let credential = provider(&sts).credential().await.unwrap();
+        assert_eq!(credential.secret_id, "AKIDtmp0");
+        assert_eq!(credential.secret_key, "tmpkey");
+        assert_eq!(credential.token.as_deref(), Some("token0"));
+
+        let requests = sts.requests_for("AssumeRole");
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].region.as_deref(), Some("ap-shanghai"));
+        assert!(requests[0].header("Authorization").unwrap().contains("Credential=base_id/"));
+        let payload: serde_json::Value = serde_json::from_str(&requests[0].payload).unwrap();
+        assert_eq!(
+            payload,
+            json!({
+                "RoleArn": "qcs::cam::uin/100:roleName/tenant",
+                "RoleSessionName": "session",
+                "DurationSeconds": 900,
+                "ExternalId": "ext-1"
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_assume_role_credential_is_reused() {
+        let sts = sts_mock(3600);
+        let api = MockTransport::new();
+        let client = tenant_client(&sts, &api);
+        call(&client).await;
+        call(&client).await;
+
+        assert_eq!(sts.requests_for("AssumeRole").len(), 1);
+        for request in api.requests_for("DescribeKeyPairs") {
+            assert_eq!(request.header("X-TC-Token"), Some("token0"));
+            assert!(request.header("Authorization").unwrap().contains("Credential=AKIDtmp0/"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_assume_role_renews_before_expiry() {
+        // 有效期短于默认的提前刷新时间，每次请求前都会重新扮演角色
+        let sts = sts_mock(60);
+        let api = MockTransport::new();
+        let client = tenant_client(&sts, &api);
+        call(&client).await;
+        call(&client).await;
+
+        assert_eq!(sts.requests_for("AssumeRole").len(), 2);
+        let tokens: Vec<_> = api
+            .requests_for("DescribeKeyPairs")
+            .iter()
+            .map(|request| request.header("X-TC-Token").unwrap().to_string())
+            .collect();
+        assert_eq!(tokens, ["token0", "token1"]);
+    }
+}
@@ -0,0 +1,181 @@
+//! CVM实例角色凭证提供者
+//!
+//! 通过实例元数据服务获取绑定在CVM实例上的CAM角色的临时凭证，
+//! 接口地址为 `http://metadata.tencentyun.com/latest/meta-data/cam/security-credentials/<role>`
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+use std::time::Duration;
+
+use super::{Credential, CredentialProvider};
+use crate::error::{Error, Result};
+
+/// 默认的元数据服务地址
+pub const DEFAULT_METADATA_ENDPOINT: &str = "http://metadata.tencentyun.com";
+
+/// 安全凭证路径
+const SECURITY_CREDENTIALS_PATH: &str = "/latest/meta-data/cam/security-credentials/";
+
+/// 元数据服务返回的临时凭证
+#[derive(Debug, Deserialize)]
+struct SecurityCredentials {
+    /// 临时密钥ID
+    TmpSecretId: String,
+    /// 临时密钥Key
+    TmpSecretKey: String,
+    /// 会话令牌
+    Token: String,
+    /// 过期时间（Unix时间戳，秒）
+    ExpiredTime: u64,
+    /// 返回码，成功时为Success
+    #[serde(default)]
+    Code: Option<String>,
+}
+
+/// CVM实例角色凭证提供者
+///
+/// 未指定角色名称时，会先从元数据服务查询实例绑定的角色。
+/// 返回的临时凭证由客户端缓存，并在过期前自动刷新。
+#[derive(Debug, Clone)]
+pub struct CvmRoleCredentialProvider {
+    endpoint: String,
+    role_name: Option<String>,
+    client: Client,
+}
+
+impl CvmRoleCredentialProvider {
+    /// 创建新的CVM实例角色凭证提供者
+    pub fn new() -> Self {
+        let client = Client::builder()
+            .connect_timeout(Duration::from_secs(1))
+            .timeout(Duration::from_secs(5))
+            .build()
+            .expect("无法创建HTTP客户端");
+        Self {
+            endpoint: DEFAULT_METADATA_ENDPOINT.to_string(),
+            role_name: None,
+            client,
+        }
+    }
+
+    /// 设置元数据服务地址（主要用于测试）
+    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = endpoint.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// 设置角色名称
+    pub fn role_name(mut self, role_name: impl Into<String>) -> Self {
+        self.role_name = Some(role_name.into());
+        self
+    }
+
+    /// 请求元数据服务并返回响应文本
+    async fn get(&self, path: &str) -> Result<String> {
+        let url = format!("{}{}", self.endpoint, path);
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| Error::CredentialError(format!("无法访问元数据服务{}: {}", url, e)))?;
+
+        let status = response.status();
+        let text = response.text().await?;
+        if !status.is_success() {
+            return Err(Error::CredentialError(format!("元数据服务返回错误状态{}: {}", status, text)));
+        }
+        Ok(text)
+    }
+
+    /// 获取角色名称，未指定时查询实例绑定的角色
+    async fn resolve_role_name(&self) -> Result<String> {
+        if let Some(role_name) = &self.role_name {
+            return Ok(role_name.clone());
+        }
+
+        let text = self.get(SECURITY_CREDENTIALS_PATH).await?;
+        text.lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .map(str::to_string)
+            .ok_or_else(|| Error::CredentialError("实例未绑定CAM角色".to_string()))
+    }
+}
+
+impl Default for CvmRoleCredentialProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for CvmRoleCredentialProvider {
+    async fn credential(&self) -> Result<Credential> {
+        let role_name = self.resolve_role_name().await?;
+        let text = self.get(&format!("{}{}", SECURITY_CREDENTIALS_PATH, role_name)).await?;
+        let credentials: SecurityCredentials = serde_json::from_str(&text)?;
+
+        if let Some(code) = &credentials.Code {
+            if code != "Success" {
+                return Err(Error::CredentialError(format!("获取角色{}的临时凭证失败: {}", role_name, code)));
+            }
+        }
+
+        Ok(Credential::temporary(
+            credentials.TmpSecretId,
+            credentials.TmpSecretKey,
+            credentials.Token,
+            credentials.ExpiredTime,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_server::TestServer;
+
+    /// 启动本地元数据服务替身，按请求路径返回固定内容
+    async fn serve_metadata(routes: Vec<(&'static str, &'static str)>) -> TestServer {
+        TestServer::start(move |request| match routes.iter().find(|(p, _)| *p == request.path) {
+            Some((_, body)) => (200, body.to_string()),
+            None => (404, "not found".to_string()),
+        })
+        .await
+    }
+
+    const CREDENTIALS: &str = r#"{"TmpSecretId":"AKIDtmp","TmpSecretKey":"tmpkey","ExpiredTime":1615590047,"Expiration":"2021-03-12T23:00:47Z","Token":"tmptoken","Code":"Success"}"#;
+
+    #[tokio::test]
+    async fn test_discovers_role_and_fetches_credential() {
+        let server = serve_metadata(vec![
+            ("/latest/meta-data/cam/security-credentials/", "worker-role"),
+            ("/latest/meta-data/cam/security-credentials/worker-role", CREDENTIALS),
+        ])
+        .await;
+
+        let credential = CvmRoleCredentialProvider::new()
+            .endpoint(server.url.clone())
+            .credential()
+            .await
+            .unwrap();
+        assert_eq!(credential.secret_id, "AKIDtmp");
+        assert_eq!(credential.secret_key, "tmpkey");
+        assert_eq!(credential.token.as_deref(), Some("tmptoken"));
+        assert_eq!(credential.expired_time, Some(1615590047));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_role_is_error() {
+        let server = serve_metadata(vec![]).await;
+        let err = CvmRoleCredentialProvider::new()
+            .endpoint(server.url.clone())
+            .role_name("missing")
+            .credential()
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::CredentialError(_)));
+    }
+}
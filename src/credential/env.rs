@@ -0,0 +1,48 @@
+//! 环境变量凭证提供者
+//!
+//! 从 `TENCENTCLOUD_SECRET_ID` 和 `TENCENTCLOUD_SECRET_KEY` 环境变量读取凭证，
+//! 若设置了 `TENCENTCLOUD_SECURITY_TOKEN` 则作为临时凭证的会话令牌
+
+use async_trait::async_trait;
+
+use super::{Credential, CredentialProvider};
+use crate::error::{Error, Result};
+
+/// 密钥ID环境变量名
+pub const ENV_SECRET_ID: &str = "TENCENTCLOUD_SECRET_ID";
+
+/// 密钥Key环境变量名
+pub const ENV_SECRET_KEY: &str = "TENCENTCLOUD_SECRET_KEY";
+
+/// 会话令牌环境变量名
+pub const ENV_SECURITY_TOKEN: &str = "TENCENTCLOUD_SECURITY_TOKEN";
+
+/// 环境变量凭证提供者
+#[derive(Debug, Clone, Default)]
+pub struct EnvCredentialProvider;
+
+impl EnvCredentialProvider {
+    /// 创建新的环境变量凭证提供者
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// 读取非空的环境变量
+fn read_env(name: &str) -> Result<String> {
+    match std::env::var(name) {
+        Ok(value) if !value.is_empty() => Ok(value),
+        _ => Err(Error::CredentialError(format!("环境变量{}未设置", name))),
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for EnvCredentialProvider {
+    async fn credential(&self) -> Result<Credential> {
+        let secret_id = read_env(ENV_SECRET_ID)?;
+        let secret_key = read_env(ENV_SECRET_KEY)?;
+        let mut credential = Credential::new(secret_id, secret_key);
+        credential.token = read_env(ENV_SECURITY_TOKEN).ok();
+        Ok(credential)
+    }
+}
@@ -0,0 +1,288 @@
+//! 凭证模块
+//!
+//! 包含凭证类型、凭证提供者接口以及默认的凭证提供者链
+
+#[cfg(feature = "sts")]
+pub mod assume_role;
+pub mod cvm_role;
+pub mod env;
+pub mod profile;
+
+use async_trait::async_trait;
+use std::fmt;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+use crate::error::{Error, Result};
+use crate::utils::signature::get_timestamp;
+
+#[cfg(feature = "sts")]
+pub use assume_role::AssumeRoleCredentialProvider;
+pub use cvm_role::CvmRoleCredentialProvider;
+pub use env::EnvCredentialProvider;
+pub use profile::ProfileCredentialProvider;
+
+/// 腾讯云API访问凭证
+#[derive(Clone)]
+pub struct Credential {
+    /// 密钥ID
+    pub secret_id: String,
+    /// 密钥Key
+    pub secret_key: String,
+    /// 临时凭证的会话令牌，请求时通过`X-TC-Token`头传递
+    pub token: Option<String>,
+    /// 临时凭证的过期时间（Unix时间戳，秒），为空表示长期有效
+    pub expired_time: Option<u64>,
+}
+
+impl Credential {
+    /// 创建新的长期凭证
+    pub fn new(secret_id: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        Self {
+            secret_id: secret_id.into(),
+            secret_key: secret_key.into(),
+            token: None,
+            expired_time: None,
+        }
+    }
+
+    /// 创建新的临时凭证，如STS或CAM角色颁发的临时密钥
+    pub fn temporary(
+        secret_id: impl Into<String>,
+        secret_key: impl Into<String>,
+        token: impl Into<String>,
+        expired_time: u64,
+    ) -> Self {
+        Self {
+            secret_id: secret_id.into(),
+            secret_key: secret_key.into(),
+            token: Some(token.into()),
+            expired_time: Some(expired_time),
+        }
+    }
+
+    /// 判断凭证是否会在指定时间内过期
+    pub fn expires_within(&self, window: Duration) -> bool {
+        match self.expired_time {
+            Some(expired_time) => get_timestamp() + window.as_secs() >= expired_time,
+            None => false,
+        }
+    }
+}
+
+impl fmt::Debug for Credential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // 不输出密钥Key，避免泄露到日志中
+        f.debug_struct("Credential")
+            .field("secret_id", &self.secret_id)
+            .field("secret_key", &"******")
+            .field("token", &self.token.as_ref().map(|_| "******"))
+            .field("expired_time", &self.expired_time)
+            .finish()
+    }
+}
+
+/// 凭证提供者
+///
+/// 每次发送请求前，客户端都会通过凭证提供者获取凭证
+#[async_trait]
+pub trait CredentialProvider: Send + Sync {
+    /// 获取凭证
+    async fn credential(&self) -> Result<Credential>;
+}
+
+/// 静态凭证提供者，始终返回固定的凭证
+#[derive(Debug, Clone)]
+pub struct StaticCredentialProvider {
+    credential: Credential,
+}
+
+impl StaticCredentialProvider {
+    /// 创建新的静态凭证提供者
+    pub fn new(secret_id: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        Self {
+            credential: Credential::new(secret_id, secret_key),
+        }
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for StaticCredentialProvider {
+    async fn credential(&self) -> Result<Credential> {
+        Ok(self.credential.clone())
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for Credential {
+    async fn credential(&self) -> Result<Credential> {
+        Ok(self.clone())
+    }
+}
+
+/// 凭证提供者链
+///
+/// 按顺序依次尝试每个凭证提供者，返回第一个成功获取的凭证
+pub struct CredentialChain {
+    providers: Vec<Box<dyn CredentialProvider>>,
+}
+
+impl CredentialChain {
+    /// 创建空的凭证提供者链
+    pub fn new() -> Self {
+        Self { providers: Vec::new() }
+    }
+
+    /// 在链尾追加凭证提供者
+    pub fn with_provider<P: CredentialProvider + 'static>(mut self, provider: P) -> Self {
+        self.providers.push(Box::new(provider));
+        self
+    }
+}
+
+impl Default for CredentialChain {
+    /// 默认凭证提供者链：环境变量 -> 配置文件 -> CVM实例角色
+    fn default() -> Self {
+        Self::new()
+            .with_provider(EnvCredentialProvider::new())
+            .with_provider(ProfileCredentialProvider::new())
+            .with_provider(CvmRoleCredentialProvider::new())
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for CredentialChain {
+    async fn credential(&self) -> Result<Credential> {
+        let mut errors = Vec::new();
+        for provider in &self.providers {
+            match provider.credential().await {
+                Ok(credential) => return Ok(credential),
+                Err(err) => errors.push(err.to_string()),
+            }
+        }
+
+        if errors.is_empty() {
+            return Err(Error::CredentialError("凭证提供者链为空".to_string()));
+        }
+        Err(Error::CredentialError(format!("无法从凭证提供者链获取凭证: {}", errors.join("; "))))
+    }
+}
+
+/// 默认的凭证提前刷新时间
+pub const DEFAULT_REFRESH_WINDOW: Duration = Duration::from_secs(300);
+
+/// 带缓存的凭证提供者
+///
+/// 缓存内部提供者返回的凭证，临时凭证在过期前的刷新窗口内会重新获取，
+/// 长期凭证获取一次后一直复用
+pub struct RefreshingCredentialProvider {
+    inner: Box<dyn CredentialProvider>,
+    refresh_window: Duration,
+    cached: Mutex<Option<Credential>>,
+}
+
+impl RefreshingCredentialProvider {
+    /// 创建新的带缓存的凭证提供者
+    pub fn new<P: CredentialProvider + 'static>(provider: P) -> Self {
+        Self {
+            inner: Box::new(provider),
+            refresh_window: DEFAULT_REFRESH_WINDOW,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// 设置凭证提前刷新时间
+    pub fn refresh_window(mut self, refresh_window: Duration) -> Self {
+        self.refresh_window = refresh_window;
+        self
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for RefreshingCredentialProvider {
+    async fn credential(&self) -> Result<Credential> {
+        // 持有锁进行刷新，避免并发请求重复获取凭证
+        let mut cached = self.cached.lock().await;
+        if let Some(credential) = cached.as_ref() {
+            if !credential.expires_within(self.refresh_window) {
+                return Ok(credential.clone());
+            }
+        }
+
+        let credential = self.inner.credential().await?;
+        *cached = Some(credential.clone());
+        Ok(credential)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FailingProvider;
+
+    #[async_trait]
+    impl CredentialProvider for FailingProvider {
+        async fn credential(&self) -> Result<Credential> {
+            Err(Error::CredentialError("failing".to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chain_falls_through_to_next_provider() {
+        let chain = CredentialChain::new()
+            .with_provider(FailingProvider)
+            .with_provider(StaticCredentialProvider::new("test_id", "test_key"));
+        let credential = chain.credential().await.unwrap();
+        assert_eq!(credential.secret_id, "test_id");
+        assert_eq!(credential.secret_key, "test_key");
+    }
+
+    #[tokio::test]
+    async fn test_chain_reports_all_errors() {
+        let chain = CredentialChain::new().with_provider(FailingProvider);
+        let err = chain.credential().await.unwrap_err();
+        assert!(matches!(err, Error::CredentialError(ref msg) if msg.contains("failing")));
+    }
+
+    #[test]
+    fn test_debug_hides_secret_key() {
+        let credential = Credential::temporary("test_id", "test_key", "test_token", 0);
+        let output = format!("{:?}", credential);
+        assert!(!output.contains("test_key"));
+        assert!(!output.contains("test_token"));
+    }
+
+    struct CountingProvider {
+        calls: std::sync::atomic::AtomicU64,
+        lifetime: u64,
+    }
+
+    #[async_trait]
+    impl CredentialProvider for CountingProvider {
+        async fn credential(&self) -> Result<Credential> {
+            let n = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(Credential::temporary(format!("id{}", n), "key", "token", get_timestamp() + self.lifetime))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refreshing_provider_caches_until_expiry() {
+        let provider = RefreshingCredentialProvider::new(CountingProvider {
+            calls: Default::default(),
+            lifetime: 3600,
+        });
+        assert_eq!(provider.credential().await.unwrap().secret_id, "id0");
+        assert_eq!(provider.credential().await.unwrap().secret_id, "id0");
+    }
+
+    #[tokio::test]
+    async fn test_refreshing_provider_refreshes_near_expiry() {
+        let provider = RefreshingCredentialProvider::new(CountingProvider {
+            calls: Default::default(),
+            lifetime: 60,
+        });
+        assert_eq!(provider.credential().await.unwrap().secret_id, "id0");
+        assert_eq!(provider.credential().await.unwrap().secret_id, "id1");
+    }
+}
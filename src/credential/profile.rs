@@ -0,0 +1,171 @@
+//! 配置文件凭证提供者
+//!
+//! 从 tccli 使用的 `~/.tencentcloud/credentials` 文件读取凭证，文件格式如下：
+//!
+//! ```ini
+//! [default]
+//! secret_id = AKIDxxxxxxxx
+//! secret_key = xxxxxxxx
+//! # 可选，临时凭证的会话令牌
+//! token = xxxxxxxx
+//! ```
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use super::{Credential, CredentialProvider};
+use crate::error::{Error, Result};
+
+/// 凭证文件路径环境变量名，设置后将覆盖默认路径
+pub const ENV_CREDENTIALS_FILE: &str = "TENCENTCLOUD_CREDENTIALS_FILE";
+
+/// 配置名称环境变量名，默认为`default`
+pub const ENV_PROFILE: &str = "TENCENTCLOUD_PROFILE";
+
+/// 默认配置名称
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// 配置文件凭证提供者
+#[derive(Debug, Clone, Default)]
+pub struct ProfileCredentialProvider {
+    path: Option<PathBuf>,
+    profile: Option<String>,
+}
+
+impl ProfileCredentialProvider {
+    /// 创建新的配置文件凭证提供者，使用默认路径和配置名称
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置凭证文件路径
+    pub fn path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// 设置配置名称
+    pub fn profile(mut self, profile: impl Into<String>) -> Self {
+        self.profile = Some(profile.into());
+        self
+    }
+
+    /// 获取凭证文件路径
+    fn resolve_path(&self) -> Result<PathBuf> {
+        if let Some(path) = &self.path {
+            return Ok(path.clone());
+        }
+        if let Ok(path) = std::env::var(ENV_CREDENTIALS_FILE) {
+            if !path.is_empty() {
+                return Ok(PathBuf::from(path));
+            }
+        }
+        let home = std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))
+            .ok_or_else(|| Error::CredentialError("无法确定用户主目录".to_string()))?;
+        Ok(PathBuf::from(home).join(".tencentcloud").join("credentials"))
+    }
+
+    /// 获取配置名称
+    fn resolve_profile(&self) -> String {
+        if let Some(profile) = &self.profile {
+            return profile.clone();
+        }
+        match std::env::var(ENV_PROFILE) {
+            Ok(profile) if !profile.is_empty() => profile,
+            _ => DEFAULT_PROFILE.to_string(),
+        }
+    }
+}
+
+/// 解析INI格式的凭证文件，返回各配置节的键值对
+pub(crate) fn parse_ini(content: &str) -> HashMap<String, HashMap<String, String>> {
+    let mut sections: HashMap<String, HashMap<String, String>> = HashMap::new();
+    let mut current = String::new();
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            current = name.trim().to_string();
+            sections.entry(current.clone()).or_default();
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            sections
+                .entry(current.clone())
+                .or_default()
+                .insert(key.trim().to_string(), value.trim().to_string());
+        }
+    }
+
+    sections
+}
+
+/// 读取凭证文件并解析指定配置的凭证
+fn load_credential(path: &Path, profile: &str) -> Result<Credential> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| Error::CredentialError(format!("无法读取凭证文件{}: {}", path.display(), e)))?;
+    let sections = parse_ini(&content);
+
+    let section = sections
+        .get(profile)
+        .ok_or_else(|| Error::CredentialError(format!("凭证文件中不存在配置{}", profile)))?;
+
+    match (section.get("secret_id"), section.get("secret_key")) {
+        (Some(secret_id), Some(secret_key)) if !secret_id.is_empty() && !secret_key.is_empty() => {
+            let mut credential = Credential::new(secret_id.clone(), secret_key.clone());
+            credential.token = section.get("token").filter(|t| !t.is_empty()).cloned();
+            Ok(credential)
+        }
+        _ => Err(Error::CredentialError(format!("配置{}缺少secret_id或secret_key", profile))),
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for ProfileCredentialProvider {
+    async fn credential(&self) -> Result<Credential> {
+        let path = self.resolve_path()?;
+        let profile = self.resolve_profile();
+
+        // 在阻塞线程池中读取文件，避免阻塞异步运行时的工作线程
+        tokio::task::spawn_blocking(move || load_credential(&path, &profile))
+            .await
+            .map_err(|e| Error::CredentialError(format!("读取凭证文件的任务失败: {}", e)))?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ini() {
+        let content = "# comment\n[default]\nsecret_id = id1\nsecret_key=key1\n\n[prod]\nsecret_id = id2\n";
+        let sections = parse_ini(content);
+        assert_eq!(sections["default"]["secret_id"], "id1");
+        assert_eq!(sections["default"]["secret_key"], "key1");
+        assert_eq!(sections["prod"]["secret_id"], "id2");
+        assert!(!sections["prod"].contains_key("secret_key"));
+    }
+
+    #[tokio::test]
+    async fn test_profile_provider_reads_named_profile() {
+        let path = std::env::temp_dir().join(format!("tencentcloud_credentials_{}", std::process::id()));
+        std::fs::write(&path, "[default]\nsecret_id = id1\nsecret_key = key1\n[prod]\nsecret_id = id2\nsecret_key = key2\n").unwrap();
+
+        let credential = ProfileCredentialProvider::new()
+            .path(&path)
+            .profile("prod")
+            .credential()
+            .await
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(credential.secret_id, "id2");
+        assert_eq!(credential.secret_key, "key2");
+    }
+}
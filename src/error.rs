@@ -1,3 +1,4 @@
+use std::fmt;
 use thiserror::Error;
 
 /// 腾讯云API SDK错误类型
@@ -8,25 +9,224 @@ pub enum Error {
     RequestError(#[from] reqwest::Error),
 
     /// 服务器返回错误
-    #[error("腾讯云API错误: {code} - {message}")]
+    #[error("腾讯云API错误: {code} - {message} (RequestId: {request_id}, HTTP状态码: {status})")]
     ApiError {
-        code: String,
+        /// 错误码
+        code: ErrorCode,
+        /// 错误信息
+        message: String,
+        /// 唯一请求ID，向腾讯云提交工单时需要提供
+        request_id: String,
+        /// HTTP状态码
+        status: u16,
+    },
+
+    /// HTTP状态码错误
+    #[error("HTTP状态码错误: {status} - {message}")]
+    HttpStatusError {
+        status: u16,
         message: String,
     },
 
     /// JSON解析错误
     #[error("JSON解析错误: {0}")]
     SerdeError(#[from] serde_json::Error),
-    
+
     /// HTTP头解析错误
     #[error("HTTP头解析错误: {0}")]
     HeaderError(String),
 
+    /// 凭证错误
+    #[error("凭证错误: {0}")]
+    CredentialError(String),
+
+    /// 请求参数错误，如缺少必填参数
+    #[error("请求参数错误: {0}")]
+    ParameterError(String),
+
+    /// 等待实例状态失败，如超时或实例进入失败状态
+    #[error("等待实例状态失败: {0}")]
+    WaiterError(String),
+
     /// 其他错误
     #[error("其他错误: {0}")]
     Other(String),
 }
 
+impl Error {
+    /// 获取API错误码
+    pub fn code(&self) -> Option<&ErrorCode> {
+        match self {
+            Error::ApiError { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+
+    /// 获取API错误的唯一请求ID
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Error::ApiError { request_id, .. } => Some(request_id),
+            _ => None,
+        }
+    }
+
+    /// 是否为鉴权失败（AuthFailure.*）
+    pub fn is_auth_failure(&self) -> bool {
+        self.code().is_some_and(ErrorCode::is_auth_failure)
+    }
+
+    /// 是否为请求频率超限（RequestLimitExceeded.*）
+    pub fn is_throttling(&self) -> bool {
+        self.code().is_some_and(ErrorCode::is_throttling)
+    }
+
+    /// 是否为可重试的错误：连接错误、超时、5xx响应或可重试的API错误码
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::RequestError(err) => err.is_connect() || err.is_timeout(),
+            Error::HttpStatusError { status, .. } => *status >= 500,
+            Error::ApiError { code, .. } => code.is_retryable(),
+            _ => false,
+        }
+    }
+}
+
+/// 定义错误码枚举，每个一级错误码可以携带二级错误码
+macro_rules! error_codes {
+    ($($(#[$doc:meta])* $variant:ident),* $(,)?) => {
+        /// 腾讯云API错误码
+        ///
+        /// 按一级错误码分类，二级错误码（如`AuthFailure.SignatureExpire`中的`SignatureExpire`）
+        /// 保存在变体中。无法识别的错误码保存在`Unknown`中。
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum ErrorCode {
+            $($(#[$doc])* $variant(Option<String>),)*
+            /// 未识别的错误码
+            Unknown(String),
+        }
+
+        impl ErrorCode {
+            /// 获取一级错误码
+            pub fn category(&self) -> &str {
+                match self {
+                    $(ErrorCode::$variant(_) => stringify!($variant),)*
+                    ErrorCode::Unknown(code) => code.split('.').next().unwrap_or(code),
+                }
+            }
+
+            /// 获取二级错误码
+            pub fn sub_code(&self) -> Option<&str> {
+                match self {
+                    $(ErrorCode::$variant(sub_code) => sub_code.as_deref(),)*
+                    ErrorCode::Unknown(code) => code.split_once('.').map(|(_, sub)| sub),
+                }
+            }
+        }
+
+        impl From<&str> for ErrorCode {
+            fn from(code: &str) -> Self {
+                let (category, sub_code) = match code.split_once('.') {
+                    Some((category, sub_code)) => (category, Some(sub_code.to_string())),
+                    None => (code, None),
+                };
+                match category {
+                    $(stringify!($variant) => ErrorCode::$variant(sub_code),)*
+                    _ => ErrorCode::Unknown(code.to_string()),
+                }
+            }
+        }
+    };
+}
+
+error_codes! {
+    /// 鉴权失败，如签名过期、密钥不存在
+    AuthFailure,
+    /// 操作失败
+    FailedOperation,
+    /// 内部错误
+    InternalError,
+    /// 接口不存在
+    InvalidAction,
+    /// 参数错误
+    InvalidParameter,
+    /// 参数取值错误
+    InvalidParameterValue,
+    /// 请求体不合法
+    InvalidRequest,
+    /// IP地址在黑名单中
+    IpInBlacklist,
+    /// 超过配额限制
+    LimitExceeded,
+    /// 缺少参数
+    MissingParameter,
+    /// 产品不存在
+    NoSuchProduct,
+    /// 不支持该版本
+    NoSuchVersion,
+    /// 操作被拒绝
+    OperationDenied,
+    /// 请求频率超限
+    RequestLimitExceeded,
+    /// 资源被占用
+    ResourceInUse,
+    /// 资源不足
+    ResourceInsufficient,
+    /// 资源不存在
+    ResourceNotFound,
+    /// 资源不可用
+    ResourceUnavailable,
+    /// 资源售罄
+    ResourcesSoldOut,
+    /// 资源繁忙
+    ResourceBusy,
+    /// 服务不可用
+    ServiceUnavailable,
+    /// 未授权操作
+    UnauthorizedOperation,
+    /// 未知参数
+    UnknownParameter,
+    /// 不支持的地域
+    UnsupportedRegion,
+    /// 不支持的操作
+    UnsupportedOperation,
+    /// 不支持的协议
+    UnsupportedProtocol,
+}
+
+impl ErrorCode {
+    /// 是否为鉴权失败（AuthFailure.*）
+    pub fn is_auth_failure(&self) -> bool {
+        matches!(self, ErrorCode::AuthFailure(_))
+    }
+
+    /// 是否为请求频率超限（RequestLimitExceeded.*）
+    ///
+    /// 注意`LimitExceeded.*`表示配额超限，不属于频率限制
+    pub fn is_throttling(&self) -> bool {
+        matches!(self, ErrorCode::RequestLimitExceeded(_))
+    }
+
+    /// 是否为可重试的错误码（RequestLimitExceeded、InternalError、ResourceBusy）
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            ErrorCode::RequestLimitExceeded(_) | ErrorCode::InternalError(_) | ErrorCode::ResourceBusy(_)
+        )
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorCode::Unknown(code) => write!(f, "{}", code),
+            _ => match self.sub_code() {
+                Some(sub_code) => write!(f, "{}.{}", self.category(), sub_code),
+                None => write!(f, "{}", self.category()),
+            },
+        }
+    }
+}
+
 /// 结果类型别名
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -40,4 +240,49 @@ impl From<http::header::InvalidHeaderValue> for Error {
     fn from(err: http::header::InvalidHeaderValue) -> Self {
         Error::HeaderError(format!("无效的头值: {}", err))
     }
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_code() {
+        let code = ErrorCode::from("AuthFailure.SignatureExpire");
+        assert_eq!(code, ErrorCode::AuthFailure(Some("SignatureExpire".to_string())));
+        assert!(code.is_auth_failure());
+        assert_eq!(code.to_string(), "AuthFailure.SignatureExpire");
+
+        let code = ErrorCode::from("InvalidParameterValue.InvalidImageId");
+        assert_eq!(code.category(), "InvalidParameterValue");
+        assert_eq!(code.sub_code(), Some("InvalidImageId"));
+
+        let code = ErrorCode::from("RequestLimitExceeded");
+        assert!(code.is_throttling());
+        assert!(code.is_retryable());
+        assert!(!ErrorCode::from("LimitExceeded.InstanceQuota").is_throttling());
+    }
+
+    #[test]
+    fn test_unknown_error_code() {
+        let code = ErrorCode::from("SomethingNew.Detail");
+        assert_eq!(code, ErrorCode::Unknown("SomethingNew.Detail".to_string()));
+        assert_eq!(code.category(), "SomethingNew");
+        assert_eq!(code.sub_code(), Some("Detail"));
+        assert_eq!(code.to_string(), "SomethingNew.Detail");
+    }
+
+    #[test]
+    fn test_api_error_helpers() {
+        let err = Error::ApiError {
+            code: ErrorCode::from("AuthFailure.SecretIdNotFound"),
+            message: "secret id not found".to_string(),
+            request_id: "req-1".to_string(),
+            status: 200,
+        };
+        assert!(err.is_auth_failure());
+        assert!(!err.is_retryable());
+        assert_eq!(err.request_id(), Some("req-1"));
+        assert!(err.to_string().contains("req-1"));
+    }
+}
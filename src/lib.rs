@@ -4,14 +4,32 @@
 //! 
 //! 本库提供与腾讯云API进行交互的Rust实现
 
+pub mod action;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod client;
+pub mod config;
+pub mod credential;
 pub mod error;
+#[cfg(feature = "mock-server")]
+pub mod mock_server;
+pub mod paginator;
+pub mod rate_limit;
+pub mod retry;
 pub mod services;
+pub mod transport;
 pub mod utils;
 
 // 重新导出
+pub use action::Action;
 pub use client::TencentCloudClient;
-pub use error::Error;
+pub use config::ClientConfig;
+pub use credential::{Credential, CredentialProvider};
+pub use error::{Error, ErrorCode};
+pub use rate_limit::{RateLimit, RateLimiter};
+pub use retry::RetryPolicy;
+pub use transport::{MockTransport, Transport};
+pub use utils::signature::RequestStyle;
 
 #[cfg(test)]
 mod tests {
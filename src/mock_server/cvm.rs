@@ -0,0 +1,547 @@
+//! 模拟CVM服务的实例状态和接口实现
+//!
+//! 实例状态保存在内存中，启动、关闭、重启、退还等操作先进入中间状态，
+//! 经过配置的延迟后在下一次请求时变为目标状态
+
+use chrono::Utc;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use super::Fault;
+use crate::services::cvm::instance::InstanceState;
+
+/// 单次请求最多操作或查询的实例数量
+const MAX_INSTANCE_IDS: usize = 100;
+
+/// 单次RunInstances最多创建的实例数量
+const MAX_INSTANCE_COUNT: i64 = 100;
+
+/// 地域信息：地域、地域名称、可用区名称前缀、可用区ID基数、可用区编号
+const REGIONS: &[(&str, &str, &str, u32, &[u32])] = &[
+    ("ap-guangzhou", "华南地区(广州)", "广州", 100000, &[3, 4, 6, 7]),
+    ("ap-shanghai", "华东地区(上海)", "上海", 200000, &[2, 3, 4, 5]),
+    ("ap-beijing", "华北地区(北京)", "北京", 800000, &[3, 5, 6, 7]),
+    ("ap-chengdu", "西南地区(成都)", "成都", 160000, &[1, 2]),
+    ("ap-hongkong", "港澳台地区(中国香港)", "香港", 300000, &[2, 3]),
+];
+
+/// 可用区编号对应的中文数字
+const ZONE_NUMBERS: &[&str] = &["零", "一", "二", "三", "四", "五", "六", "七", "八", "九"];
+
+/// 模拟的实例
+#[derive(Debug, Clone)]
+struct MockInstance {
+    id: String,
+    region: String,
+    zone: String,
+    project_id: i64,
+    name: String,
+    image_id: String,
+    instance_type: String,
+    charge_type: String,
+    state: InstanceState,
+    /// 等待生效的目标状态和生效时间，目标状态为空表示实例将被销毁
+    pending: Option<(Option<InstanceState>, Instant)>,
+    created_time: String,
+    system_disk_type: String,
+    system_disk_size: i64,
+    vpc_id: String,
+    subnet_id: String,
+    private_ip: String,
+    public_ip: Option<String>,
+    security_group_ids: Vec<String>,
+    tags: Vec<(String, String)>,
+}
+
+impl MockInstance {
+    /// 转换为DescribeInstances返回的实例详情
+    fn to_json(&self) -> Value {
+        let (cpu, memory) = instance_type_spec(&self.instance_type);
+        json!({
+            "InstanceId": self.id,
+            "InstanceName": self.name,
+            "InstanceType": self.instance_type,
+            "InstanceChargeType": self.charge_type,
+            "InstanceState": self.state.as_str(),
+            "CPU": cpu,
+            "Memory": memory,
+            "CreatedTime": self.created_time,
+            "ExpiredTime": null,
+            "ImageId": self.image_id,
+            "OsName": "TencentOS Server 3.2 (Final)",
+            "SecurityGroupIds": self.security_group_ids,
+            "PrivateIpAddresses": [self.private_ip],
+            "PublicIpAddresses": self.public_ip.as_ref().map(|ip| vec![ip]),
+            "Placement": {"Zone": self.zone, "ProjectId": self.project_id},
+            "SystemDisk": {
+                "DiskType": self.system_disk_type,
+                "DiskId": format!("disk-{}", &self.id[4..]),
+                "DiskSize": self.system_disk_size,
+            },
+            "VirtualPrivateCloud": {"VpcId": self.vpc_id, "SubnetId": self.subnet_id, "AsVpcGateway": false},
+            "Tags": self.tags.iter().map(|(key, value)| json!({"Key": key, "Value": value})).collect::<Vec<_>>(),
+        })
+    }
+
+    /// 判断实例是否匹配过滤条件，同一过滤条件的多个取值之间为或的关系
+    fn matches(&self, name: &str, values: &[String]) -> Result<bool, Fault> {
+        let any = |value: &str| values.iter().any(|v| v == value);
+        let matched = match name {
+            "zone" => any(&self.zone),
+            "project-id" => any(&self.project_id.to_string()),
+            "instance-id" => any(&self.id),
+            "instance-name" => any(&self.name),
+            "instance-charge-type" => any(&self.charge_type),
+            "instance-state" => any(self.state.as_str()),
+            "image-id" => any(&self.image_id),
+            "private-ip-address" => any(&self.private_ip),
+            "public-ip-address" => self.public_ip.as_deref().is_some_and(any),
+            "vpc-id" => any(&self.vpc_id),
+            "subnet-id" => any(&self.subnet_id),
+            "security-group-id" => self.security_group_ids.iter().any(|id| any(id)),
+            "tag-key" => self.tags.iter().any(|(key, _)| any(key)),
+            _ => match name.strip_prefix("tag:") {
+                Some(tag_key) => self.tags.iter().any(|(key, value)| key == tag_key && any(value)),
+                None => return Err(Fault::new("InvalidFilter", format!("不支持的过滤条件: {}", name))),
+            },
+        };
+        Ok(matched)
+    }
+}
+
+/// 模拟CVM服务的内存状态
+pub(crate) struct CvmState {
+    /// 按创建顺序保存的实例
+    instances: Vec<MockInstance>,
+    /// 中间状态变为目标状态的延迟
+    transition_delay: Duration,
+    /// ClientToken对应的已创建实例，用于保证RunInstances的幂等
+    client_tokens: HashMap<String, Vec<String>>,
+    /// 已分配的IP数量
+    allocated_ips: u32,
+}
+
+impl CvmState {
+    /// 创建空的状态
+    pub(crate) fn new(transition_delay: Duration) -> Self {
+        Self {
+            instances: Vec::new(),
+            transition_delay,
+            client_tokens: HashMap::new(),
+            allocated_ips: 0,
+        }
+    }
+
+    /// 处理接口请求，返回不含RequestId的响应数据
+    pub(crate) fn handle(&mut self, action: &str, region: &str, params: &Value) -> Result<Map<String, Value>, Fault> {
+        if action != "DescribeRegions" && !REGIONS.iter().any(|(name, ..)| *name == region) {
+            return Err(Fault::new("UnsupportedRegion", format!("不支持的地域: {}", region)));
+        }
+        self.settle();
+
+        let response = match action {
+            "DescribeRegions" => describe_regions(),
+            "DescribeZones" => describe_zones(region),
+            "RunInstances" => self.run_instances(region, params)?,
+            "DescribeInstances" => self.describe_instances(region, params)?,
+            "DescribeInstancesStatus" => self.describe_instances_status(region, params)?,
+            "StartInstances" => self.operate(region, params, &[InstanceState::Stopped], InstanceState::Starting, Some(InstanceState::Running))?,
+            "StopInstances" => self.operate(region, params, &[InstanceState::Running], InstanceState::Stopping, Some(InstanceState::Stopped))?,
+            "RebootInstances" => self.operate(region, params, &[InstanceState::Running], InstanceState::Rebooting, Some(InstanceState::Running))?,
+            "TerminateInstances" => self.operate(
+                region,
+                params,
+                &[InstanceState::Running, InstanceState::Stopped, InstanceState::LaunchFailed, InstanceState::Shutdown],
+                InstanceState::Terminating,
+                None,
+            )?,
+            _ => return Err(Fault::new("InvalidAction", format!("接口{}不存在", action))),
+        };
+        match response {
+            Value::Object(map) => Ok(map),
+            _ => Ok(Map::new()),
+        }
+    }
+
+    /// 把到期的中间状态变为目标状态，并移除已销毁的实例
+    fn settle(&mut self) {
+        let now = Instant::now();
+        self.instances.retain_mut(|instance| match &instance.pending {
+            Some((target, at)) if now >= *at => match target.clone() {
+                Some(state) => {
+                    instance.state = state;
+                    instance.pending = None;
+                    true
+                }
+                None => false,
+            },
+            _ => true,
+        });
+    }
+
+    /// 创建实例
+    fn run_instances(&mut self, region: &str, params: &Value) -> Result<Value, Fault> {
+        let zone = str_param(params, "/Placement/Zone")?
+            .ok_or_else(|| Fault::new("MissingParameter", "缺少参数Placement.Zone"))?;
+        if !zones(region).iter().any(|(name, ..)| *name == zone) {
+            return Err(Fault::new("InvalidZone.MismatchRegion", format!("可用区{}不属于地域{}", zone, region)));
+        }
+        let image_id = str_param(params, "/ImageId")?.ok_or_else(|| Fault::new("MissingParameter", "缺少参数ImageId"))?;
+        let count = int_param(params, "/InstanceCount")?.unwrap_or(1);
+        if !(1..=MAX_INSTANCE_COUNT).contains(&count) {
+            return Err(Fault::new(
+                "InvalidParameterValue",
+                format!("InstanceCount取值范围为1到{}", MAX_INSTANCE_COUNT),
+            ));
+        }
+        let charge_type = str_param(params, "/InstanceChargeType")?.unwrap_or_else(|| "POSTPAID_BY_HOUR".to_string());
+        if charge_type == "PREPAID" && params.get("InstanceChargePrepaid").is_none() {
+            return Err(Fault::new("MissingParameter", "包年包月实例缺少参数InstanceChargePrepaid"));
+        }
+        if params.get("DryRun").and_then(Value::as_bool) == Some(true) {
+            return Err(Fault::new("DryRunOperation", "请求参数校验通过"));
+        }
+
+        let client_token = str_param(params, "/ClientToken")?;
+        if let Some(ids) = client_token.as_ref().and_then(|token| self.client_tokens.get(token)) {
+            return Ok(json!({"InstanceIdSet": ids}));
+        }
+
+        let name = str_param(params, "/InstanceName")?.unwrap_or_else(|| "未命名".to_string());
+        let bandwidth = int_param(params, "/InternetAccessible/InternetMaxBandwidthOut")?.unwrap_or(0);
+        let public_ip_assigned = params.pointer("/InternetAccessible/PublicIpAssigned").and_then(Value::as_bool) != Some(false)
+            && bandwidth > 0;
+        let tags: Vec<(String, String)> = params
+            .get("TagSpecification")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .flat_map(|spec| spec.get("Tags").and_then(Value::as_array).cloned().unwrap_or_default())
+            .filter_map(|tag| Some((tag.get("Key")?.as_str()?.to_string(), tag.get("Value")?.as_str()?.to_string())))
+            .collect();
+
+        let mut ids = Vec::new();
+        for index in 1..=count {
+            let id = format!("ins-{:08x}", rand::random::<u32>());
+            self.allocated_ips += 1;
+            let (high, low) = (self.allocated_ips / 250, self.allocated_ips % 250 + 2);
+            self.instances.push(MockInstance {
+                id: id.clone(),
+                region: region.to_string(),
+                zone: zone.clone(),
+                project_id: int_param(params, "/Placement/ProjectId")?.unwrap_or(0),
+                name: if count > 1 { format!("{}-{}", name, index) } else { name.clone() },
+                image_id: image_id.clone(),
+                instance_type: str_param(params, "/InstanceType")?.unwrap_or_else(|| "S5.SMALL1".to_string()),
+                charge_type: charge_type.clone(),
+                state: InstanceState::Pending,
+                pending: Some((Some(InstanceState::Running), Instant::now() + self.transition_delay)),
+                created_time: Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+                system_disk_type: str_param(params, "/SystemDisk/DiskType")?.unwrap_or_else(|| "CLOUD_PREMIUM".to_string()),
+                system_disk_size: int_param(params, "/SystemDisk/DiskSize")?.unwrap_or(50),
+                vpc_id: str_param(params, "/VirtualPrivateCloud/VpcId")?.unwrap_or_else(|| "vpc-mock0001".to_string()),
+                subnet_id: str_param(params, "/VirtualPrivateCloud/SubnetId")?.unwrap_or_else(|| "subnet-mock0001".to_string()),
+                private_ip: format!("10.0.{}.{}", high, low),
+                public_ip: public_ip_assigned.then(|| format!("203.0.{}.{}", 113 + high, low)),
+                security_group_ids: str_list_param(params, "/SecurityGroupIds")?.unwrap_or_default(),
+                tags: tags.clone(),
+            });
+            ids.push(id);
+        }
+        if let Some(token) = client_token {
+            self.client_tokens.insert(token, ids.clone());
+        }
+        Ok(json!({"InstanceIdSet": ids}))
+    }
+
+    /// 查询实例列表
+    fn describe_instances(&self, region: &str, params: &Value) -> Result<Value, Fault> {
+        let instances = self.query(region, params, true)?;
+        let (total, page) = paginate(instances, params)?;
+        Ok(json!({
+            "TotalCount": total,
+            "InstanceSet": page.iter().map(|instance| instance.to_json()).collect::<Vec<_>>(),
+        }))
+    }
+
+    /// 查询实例状态列表
+    fn describe_instances_status(&self, region: &str, params: &Value) -> Result<Value, Fault> {
+        let instances = self.query(region, params, false)?;
+        let (total, page) = paginate(instances, params)?;
+        Ok(json!({
+            "TotalCount": total,
+            "InstanceStatusSet": page
+                .iter()
+                .map(|instance| json!({"InstanceId": instance.id, "InstanceState": instance.state.as_str()}))
+                .collect::<Vec<_>>(),
+        }))
+    }
+
+    /// 按实例ID或过滤条件查询地域内的实例，查询不到的实例ID会被忽略
+    fn query(&self, region: &str, params: &Value, with_filters: bool) -> Result<Vec<&MockInstance>, Fault> {
+        let instance_ids = str_list_param(params, "/InstanceIds")?;
+        let filters = if with_filters { params.get("Filters").and_then(Value::as_array) } else { None };
+        if instance_ids.is_some() && filters.is_some() {
+            return Err(Fault::new("InvalidParameterCombination", "InstanceIds和Filters不能同时指定"));
+        }
+        if instance_ids.as_ref().is_some_and(|ids| ids.len() > MAX_INSTANCE_IDS) {
+            return Err(Fault::new(
+                "InvalidParameterValue",
+                format!("InstanceIds最多指定{}个", MAX_INSTANCE_IDS),
+            ));
+        }
+
+        let mut conditions = Vec::new();
+        for filter in filters.into_iter().flatten() {
+            let name = filter.get("Name").and_then(Value::as_str).unwrap_or_default().to_string();
+            let values = str_list_param(filter, "/Values")?.unwrap_or_default();
+            conditions.push((name, values));
+        }
+
+        let mut result = Vec::new();
+        for instance in self.instances.iter().filter(|instance| instance.region == region) {
+            if instance_ids.as_ref().is_some_and(|ids| !ids.contains(&instance.id)) {
+                continue;
+            }
+            let mut matched = true;
+            for (name, values) in &conditions {
+                matched &= instance.matches(name, values)?;
+            }
+            if matched {
+                result.push(instance);
+            }
+        }
+        Ok(result)
+    }
+
+    /// 检查实例状态后让实例进入中间状态，经过延迟后变为目标状态，目标状态为空表示销毁
+    fn operate(
+        &mut self,
+        region: &str,
+        params: &Value,
+        allowed: &[InstanceState],
+        via: InstanceState,
+        target: Option<InstanceState>,
+    ) -> Result<Value, Fault> {
+        let instance_ids = str_list_param(params, "/InstanceIds")?
+            .filter(|ids| !ids.is_empty())
+            .ok_or_else(|| Fault::new("MissingParameter", "缺少参数InstanceIds"))?;
+        if instance_ids.len() > MAX_INSTANCE_IDS {
+            return Err(Fault::new(
+                "InvalidParameterValue",
+                format!("InstanceIds最多指定{}个", MAX_INSTANCE_IDS),
+            ));
+        }
+
+        // 先检查所有实例，任一实例不满足条件时不做任何修改
+        let mut indexes = Vec::with_capacity(instance_ids.len());
+        for id in &instance_ids {
+            let index = self
+                .instances
+                .iter()
+                .position(|instance| instance.id == *id && instance.region == region)
+                .ok_or_else(|| Fault::new("InvalidInstanceId.NotFound", format!("实例{}不存在", id)))?;
+            let state = &self.instances[index].state;
+            if !allowed.contains(state) {
+                return Err(Fault::new(
+                    format!("UnsupportedOperation.InstanceState{}", pascal_case(state.as_str())),
+                    format!("实例{}处于{}状态，不支持该操作", id, state),
+                ));
+            }
+            indexes.push(index);
+        }
+
+        let at = Instant::now() + self.transition_delay;
+        for index in indexes {
+            let instance = &mut self.instances[index];
+            instance.state = via.clone();
+            instance.pending = Some((target.clone(), at));
+        }
+        Ok(json!({}))
+    }
+}
+
+/// 查询地域列表
+fn describe_regions() -> Value {
+    let regions: Vec<Value> = REGIONS
+        .iter()
+        .map(|(region, name, ..)| json!({"Region": region, "RegionName": name, "RegionState": "AVAILABLE"}))
+        .collect();
+    json!({"TotalCount": regions.len(), "RegionSet": regions})
+}
+
+/// 查询可用区列表
+fn describe_zones(region: &str) -> Value {
+    let zones: Vec<Value> = zones(region)
+        .into_iter()
+        .map(|(zone, name, id)| json!({"Zone": zone, "ZoneName": name, "ZoneId": id, "ZoneState": "AVAILABLE"}))
+        .collect();
+    json!({"TotalCount": zones.len(), "ZoneSet": zones})
+}
+
+/// 获取地域的可用区、可用区名称和可用区ID
+fn zones(region: &str) -> Vec<(String, String, String)> {
+    REGIONS
+        .iter()
+        .filter(|(name, ..)| *name == region)
+        .flat_map(|(name, _, city, base, numbers)| {
+            numbers.iter().map(move |n| {
+                (
+                    format!("{}-{}", name, n),
+                    format!("{}{}区", city, ZONE_NUMBERS[*n as usize]),
+                    (base + n).to_string(),
+                )
+            })
+        })
+        .collect()
+}
+
+/// 按Offset和Limit分页，返回总数和当前页
+fn paginate<'a>(items: Vec<&'a MockInstance>, params: &Value) -> Result<(usize, Vec<&'a MockInstance>), Fault> {
+    let offset = int_param(params, "/Offset")?.unwrap_or(0);
+    let limit = int_param(params, "/Limit")?.unwrap_or(20);
+    if offset < 0 || !(1..=100).contains(&limit) {
+        return Err(Fault::new("InvalidParameterValue", "Offset不能小于0，Limit取值范围为1到100"));
+    }
+    let total = items.len();
+    let page = items.into_iter().skip(offset as usize).take(limit as usize).collect();
+    Ok((total, page))
+}
+
+/// 根据实例规格推算CPU核数和内存大小，如S5.LARGE8为4核8GB
+fn instance_type_spec(instance_type: &str) -> (i64, i64) {
+    let size = instance_type.split('.').nth(1).unwrap_or_default();
+    let letters = size.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(0);
+    let split = size[letters..]
+        .find(|c: char| c.is_ascii_digit())
+        .map_or(size.len(), |index| index + letters);
+    let (family, memory) = size.split_at(split);
+    let cpu = match family {
+        "SMALL" => 1,
+        "MEDIUM" => 2,
+        "LARGE" => 4,
+        "2XLARGE" => 8,
+        "3XLARGE" => 12,
+        "4XLARGE" => 16,
+        "6XLARGE" => 24,
+        "8XLARGE" => 32,
+        _ => 1,
+    };
+    (cpu, memory.parse().unwrap_or(cpu * 2))
+}
+
+/// 把`LAUNCH_FAILED`转换为`LaunchFailed`
+fn pascal_case(value: &str) -> String {
+    value
+        .split('_')
+        .map(|word| {
+            let lower = word.to_lowercase();
+            let mut chars = lower.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// 读取字符串参数
+fn str_param(params: &Value, pointer: &str) -> Result<Option<String>, Fault> {
+    match params.pointer(pointer) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(value)) => Ok(Some(value.clone())),
+        Some(_) => Err(invalid_type(pointer, "字符串")),
+    }
+}
+
+/// 读取整数参数
+fn int_param(params: &Value, pointer: &str) -> Result<Option<i64>, Fault> {
+    match params.pointer(pointer) {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => value.as_i64().map(Some).ok_or_else(|| invalid_type(pointer, "整数")),
+    }
+}
+
+/// 读取字符串列表参数
+fn str_list_param(params: &Value, pointer: &str) -> Result<Option<Vec<String>>, Fault> {
+    match params.pointer(pointer) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::Array(items)) => items
+            .iter()
+            .map(|item| item.as_str().map(str::to_string).ok_or_else(|| invalid_type(pointer, "字符串列表")))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Some),
+        Some(_) => Err(invalid_type(pointer, "字符串列表")),
+    }
+}
+
+/// 参数类型错误
+fn invalid_type(pointer: &str, expected: &str) -> Fault {
+    Fault::new(
+        "InvalidParameter",
+        format!("参数{}应为{}", pointer.trim_start_matches('/').replace('/', "."), expected),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instance_type_spec() {
+        assert_eq!(instance_type_spec("S5.LARGE8"), (4, 8));
+        assert_eq!(instance_type_spec("S5.SMALL1"), (1, 1));
+        assert_eq!(instance_type_spec("SA2.2XLARGE16"), (8, 16));
+        assert_eq!(instance_type_spec("S5.3XLARGE32"), (12, 32));
+        assert_eq!(pascal_case("LAUNCH_FAILED"), "LaunchFailed");
+    }
+
+    #[test]
+    fn test_state_transitions() {
+        let mut state = CvmState::new(Duration::ZERO);
+        let params = json!({"Placement": {"Zone": "ap-guangzhou-6"}, "ImageId": "img-1", "InstanceCount": 2});
+        let response = state.handle("RunInstances", "ap-guangzhou", &params).unwrap();
+        let ids = response["InstanceIdSet"].clone();
+        assert_eq!(ids.as_array().unwrap().len(), 2);
+        assert_eq!(state.instances[0].state, InstanceState::Pending);
+
+        // 下一次请求时到期的中间状态生效
+        let status = state.handle("DescribeInstancesStatus", "ap-guangzhou", &json!({})).unwrap();
+        assert_eq!(status["InstanceStatusSet"][0]["InstanceState"], "RUNNING");
+
+        let err = state
+            .handle("StartInstances", "ap-guangzhou", &json!({"InstanceIds": ids}))
+            .unwrap_err();
+        assert_eq!(err.code, "UnsupportedOperation.InstanceStateRunning");
+
+        state.handle("TerminateInstances", "ap-guangzhou", &json!({"InstanceIds": ids})).unwrap();
+        let status = state.handle("DescribeInstancesStatus", "ap-guangzhou", &json!({})).unwrap();
+        assert_eq!(status["TotalCount"], 0);
+    }
+
+    #[test]
+    fn test_describe_instances_filters() {
+        let mut state = CvmState::new(Duration::ZERO);
+        let params = json!({
+            "Placement": {"Zone": "ap-guangzhou-6"},
+            "ImageId": "img-1",
+            "TagSpecification": [{"ResourceType": "instance", "Tags": [{"Key": "env", "Value": "prod"}]}],
+        });
+        state.handle("RunInstances", "ap-guangzhou", &params).unwrap();
+        let params = json!({"Placement": {"Zone": "ap-guangzhou-3"}, "ImageId": "img-1"});
+        state.handle("RunInstances", "ap-guangzhou", &params).unwrap();
+
+        let filters = json!({"Filters": [{"Name": "tag:env", "Values": ["prod"]}]});
+        let response = state.handle("DescribeInstances", "ap-guangzhou", &filters).unwrap();
+        assert_eq!(response["TotalCount"], 1);
+        assert_eq!(response["InstanceSet"][0]["Placement"]["Zone"], "ap-guangzhou-6");
+
+        let filters = json!({"Filters": [{"Name": "zones", "Values": ["ap-guangzhou-6"]}]});
+        let err = state.handle("DescribeInstances", "ap-guangzhou", &filters).unwrap_err();
+        assert_eq!(err.code, "InvalidFilter");
+
+        let response = state.handle("DescribeInstances", "ap-beijing", &json!({})).unwrap();
+        assert_eq!(response["TotalCount"], 0);
+    }
+}
@@ -0,0 +1,345 @@
+//! 本地模拟CVM服务
+//!
+//! 在本机提供CVM 2017-03-12版本的RunInstances、DescribeInstances、DescribeInstancesStatus、
+//! StartInstances、StopInstances、RebootInstances、TerminateInstances、DescribeRegions和DescribeZones接口，
+//! 实例状态保存在内存中，使用`SignatureBuilder::verify`校验TC3-HMAC-SHA256签名，用于离线的端到端测试。
+//!
+//! 也可以通过`cargo run --features mock-server --bin mock_cvm_server`单独启动。
+
+mod cvm;
+
+use serde_json::{json, Value};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+use crate::config::ClientConfig;
+use crate::credential::StaticCredentialProvider;
+use crate::utils::local_server::{self, HttpRequest};
+use crate::utils::signature::{get_timestamp, Authorization, SignatureBuilder};
+use cvm::CvmState;
+
+/// 默认的密钥ID
+pub const DEFAULT_SECRET_ID: &str = "mock-secret-id";
+
+/// 默认的密钥Key
+pub const DEFAULT_SECRET_KEY: &str = "mock-secret-key";
+
+/// 默认的状态变化延迟
+pub const DEFAULT_TRANSITION_DELAY: Duration = Duration::from_secs(2);
+
+/// 模拟的服务名称
+const SERVICE: &str = "cvm";
+
+/// 模拟的接口版本
+const VERSION: &str = "2017-03-12";
+
+/// 请求时间戳与服务器时间允许的最大误差（秒）
+const MAX_TIMESTAMP_SKEW: u64 = 300;
+
+/// 接口错误
+#[derive(Debug, Clone)]
+pub(crate) struct Fault {
+    pub code: String,
+    pub message: String,
+}
+
+impl Fault {
+    /// 创建接口错误
+    pub(crate) fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// 模拟CVM服务的构造器
+pub struct MockCvmServerBuilder {
+    addr: String,
+    secret_id: String,
+    secret_key: String,
+    transition_delay: Duration,
+}
+
+impl MockCvmServerBuilder {
+    /// 设置监听地址，默认为`127.0.0.1:0`（随机端口）
+    pub fn bind(mut self, addr: impl Into<String>) -> Self {
+        self.addr = addr.into();
+        self
+    }
+
+    /// 设置校验签名使用的密钥，默认为`mock-secret-id`和`mock-secret-key`
+    pub fn credential(mut self, secret_id: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        self.secret_id = secret_id.into();
+        self.secret_key = secret_key.into();
+        self
+    }
+
+    /// 设置实例从中间状态（如PENDING、STOPPING）变为目标状态的延迟，默认为2秒
+    pub fn transition_delay(mut self, transition_delay: Duration) -> Self {
+        self.transition_delay = transition_delay;
+        self
+    }
+
+    /// 启动服务
+    pub async fn start(self) -> std::io::Result<MockCvmServer> {
+        let listener = TcpListener::bind(&self.addr).await?;
+        let addr = listener.local_addr()?;
+        let context = Arc::new(Context {
+            secret_id: self.secret_id.clone(),
+            secret_key: self.secret_key.clone(),
+            state: Mutex::new(CvmState::new(self.transition_delay)),
+        });
+
+        let accept_context = context.clone();
+        let handle = tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                let context = accept_context.clone();
+                tokio::spawn(async move {
+                    let _ = handle_connection(socket, &context).await;
+                });
+            }
+        });
+
+        Ok(MockCvmServer {
+            addr,
+            secret_id: self.secret_id,
+            secret_key: self.secret_key,
+            handle,
+        })
+    }
+}
+
+/// 本地模拟CVM服务
+///
+/// 服务在被丢弃时停止。
+///
+/// ```no_run
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// use tencent_cloud_sdk::TencentCloudClient;
+/// use tencent_cloud_sdk::mock_server::MockCvmServer;
+///
+/// let server = MockCvmServer::builder().start().await?;
+/// let client = TencentCloudClient::with_config(server.credential_provider(), server.client_config())?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct MockCvmServer {
+    addr: SocketAddr,
+    secret_id: String,
+    secret_key: String,
+    handle: JoinHandle<()>,
+}
+
+impl MockCvmServer {
+    /// 创建模拟CVM服务的构造器
+    pub fn builder() -> MockCvmServerBuilder {
+        MockCvmServerBuilder {
+            addr: "127.0.0.1:0".to_string(),
+            secret_id: DEFAULT_SECRET_ID.to_string(),
+            secret_key: DEFAULT_SECRET_KEY.to_string(),
+            transition_delay: DEFAULT_TRANSITION_DELAY,
+        }
+    }
+
+    /// 获取监听地址
+    pub fn get_addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// 获取服务地址，如`http://127.0.0.1:12345`
+    pub fn get_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// 创建把cvm服务指向本服务的客户端配置
+    pub fn client_config(&self) -> ClientConfig {
+        ClientConfig::new().endpoint(SERVICE, self.get_url())
+    }
+
+    /// 创建使用本服务密钥的凭证提供者
+    pub fn credential_provider(&self) -> StaticCredentialProvider {
+        StaticCredentialProvider::new(self.secret_id.clone(), self.secret_key.clone())
+    }
+}
+
+impl Drop for MockCvmServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// 请求处理共享的数据
+struct Context {
+    secret_id: String,
+    secret_key: String,
+    state: Mutex<CvmState>,
+}
+
+/// 读取一个请求并写回响应
+async fn handle_connection(mut socket: TcpStream, context: &Context) -> std::io::Result<()> {
+    let Some(request) = local_server::read_request(&mut socket).await? else {
+        return Ok(());
+    };
+    let request_id = new_request_id();
+    let body = match dispatch(&request, context) {
+        Ok(mut response) => {
+            response.insert("RequestId".to_string(), Value::String(request_id));
+            json!({"Response": response})
+        }
+        Err(fault) => json!({
+            "Response": {
+                "Error": {"Code": fault.code, "Message": fault.message},
+                "RequestId": request_id,
+            }
+        }),
+    };
+    local_server::write_response(&mut socket, 200, &body.to_string()).await
+}
+
+/// 校验请求并交给CVM状态处理
+fn dispatch(request: &HttpRequest, context: &Context) -> Result<serde_json::Map<String, Value>, Fault> {
+    if request.method != "POST" {
+        return Err(Fault::new("UnsupportedProtocol", format!("不支持{}请求", request.method)));
+    }
+    if request.path != "/" {
+        return Err(Fault::new("UnsupportedProtocol", format!("请求路径{}错误，只支持/", request.path)));
+    }
+    verify_signature(request, &context.secret_id, &context.secret_key)?;
+
+    let action = request
+        .header("x-tc-action")
+        .ok_or_else(|| Fault::new("MissingParameter", "缺少请求头X-TC-Action"))?;
+    let version = request.header("x-tc-version").unwrap_or_default();
+    if version != VERSION {
+        return Err(Fault::new("NoSuchVersion", format!("接口版本{}不存在", version)));
+    }
+    let region = request.header("x-tc-region").unwrap_or_default();
+    let params: Value = if request.body.trim().is_empty() {
+        json!({})
+    } else {
+        serde_json::from_str(&request.body).map_err(|e| Fault::new("InvalidParameter", format!("请求体不是合法的JSON: {}", e)))?
+    };
+
+    context.state.lock().unwrap().handle(action, region, &params)
+}
+
+/// 使用`SignatureBuilder::verify`校验请求的Authorization
+fn verify_signature(request: &HttpRequest, secret_id: &str, secret_key: &str) -> Result<(), Fault> {
+    let header = request
+        .header("authorization")
+        .ok_or_else(|| Fault::new("AuthFailure.InvalidAuthorization", "缺少请求头Authorization"))?;
+    let authorization = Authorization::parse(header)
+        .ok_or_else(|| Fault::new("AuthFailure.InvalidAuthorization", "Authorization格式错误"))?;
+    if authorization.secret_id != secret_id {
+        return Err(Fault::new("AuthFailure.SecretIdNotFound", "密钥ID不存在"));
+    }
+    if authorization.service != SERVICE {
+        return Err(Fault::new(
+            "AuthFailure.InvalidAuthorization",
+            format!("服务名称{}错误", authorization.service),
+        ));
+    }
+
+    let timestamp = request
+        .header("x-tc-timestamp")
+        .and_then(|value| value.parse::<u64>().ok())
+        .ok_or_else(|| Fault::new("AuthFailure.InvalidAuthorization", "缺少请求头X-TC-Timestamp"))?;
+    if get_timestamp().abs_diff(timestamp) > MAX_TIMESTAMP_SKEW {
+        return Err(Fault::new("AuthFailure.SignatureExpire", "签名已过期，请检查本机时间"));
+    }
+
+    let host = request.header("host").unwrap_or_default();
+    let verified = SignatureBuilder::new(secret_id.to_string(), secret_key.to_string(), SERVICE.to_string(), host.to_string())
+        .timestamp(timestamp)
+        .payload(request.body.clone())
+        .verify(header);
+    if !verified {
+        return Err(Fault::new("AuthFailure.SignatureFailure", "签名校验失败"));
+    }
+    Ok(())
+}
+
+/// 生成UUID格式的请求ID
+fn new_request_id() -> String {
+    let value = rand::random::<u128>();
+    let hex = format!("{:032x}", value);
+    format!("{}-{}-{}-{}-{}", &hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use crate::services::cvm::instance::{InstanceService, RunInstancesRequest};
+    use crate::services::cvm::instance_operation::{InstanceOperationService, StopInstancesRequest, TerminateInstancesRequest};
+    use crate::services::cvm::instance_query::{DescribeInstancesRequest, InstanceFilters, InstanceQueryService};
+    use crate::services::cvm::instance_waiter::InstanceWaiter;
+    use crate::services::region::RegionService;
+    use crate::TencentCloudClient;
+
+    const REGION: &str = "ap-guangzhou";
+
+    #[tokio::test]
+    async fn test_instance_lifecycle() {
+        let server = MockCvmServer::builder()
+            .transition_delay(Duration::from_millis(20))
+            .start()
+            .await
+            .unwrap();
+        let client = TencentCloudClient::with_config(server.credential_provider(), server.client_config()).unwrap();
+        let waiter = InstanceWaiter::new(&client).poll_interval(Duration::from_millis(10));
+
+        let zones = RegionService::new(&client).describe_zones(REGION).await.unwrap();
+        let zone = zones.Response.ZoneSet[0].Zone.clone();
+
+        let request = RunInstancesRequest::builder()
+            .zone(zone)
+            .image_id("img-eb30mz89")
+            .instance_type("S5.MEDIUM4")
+            .instance_count(2)
+            .instance_name("web")
+            .tag("env", "test")
+            .build()
+            .unwrap();
+        let response = InstanceService::new(&client).run_instances(&request, REGION).await.unwrap();
+        let ids = response.Response.InstanceIdSet;
+        assert_eq!(ids.len(), 2);
+
+        waiter.wait_until_running(&ids, REGION).await.unwrap();
+
+        let query = DescribeInstancesRequest::with_filters(InstanceFilters::new().tag("env", "test"));
+        let instances = InstanceQueryService::new(&client).describe_instances(&query, REGION).await.unwrap();
+        assert_eq!(instances.Response.TotalCount, 2);
+        assert_eq!(instances.Response.InstanceSet[0].CPU, 2);
+        assert_eq!(instances.Response.InstanceSet[0].Memory, 4);
+
+        let operation = InstanceOperationService::new(&client);
+        let stop = StopInstancesRequest::builder().instance_ids(ids.clone()).build().unwrap();
+        operation.stop_instances(&stop, REGION).await.unwrap();
+        waiter.wait_until_stopped(&ids, REGION).await.unwrap();
+
+        let terminate = TerminateInstancesRequest::builder().instance_ids(ids.clone()).build().unwrap();
+        operation.terminate_instances(&terminate, REGION).await.unwrap();
+        waiter.wait_until_terminated(&ids, REGION).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rejects_invalid_signature() {
+        let server = MockCvmServer::builder().start().await.unwrap();
+        let client = TencentCloudClient::with_config(
+            StaticCredentialProvider::new(DEFAULT_SECRET_ID, "wrong-key"),
+            server.client_config(),
+        )
+        .unwrap();
+        let err = RegionService::new(&client).describe_regions().await.unwrap_err();
+        assert!(matches!(err, Error::ApiError { ref code, .. } if code.to_string() == "AuthFailure.SignatureFailure"));
+
+        let client = TencentCloudClient::with_config(server.credential_provider(), server.client_config()).unwrap();
+        let regions = RegionService::new(&client).describe_regions().await.unwrap();
+        assert!(regions.Response.RegionSet.iter().any(|region| region.Region == REGION));
+    }
+}
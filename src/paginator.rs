@@ -0,0 +1,240 @@
+//! 分页查询
+//!
+//! 适用于使用`Offset`/`Limit`分页、响应中返回`TotalCount`的查询接口，
+//! 自动翻页直到取完所有结果，并支持并发预取后续页
+
+use futures::future::{self, BoxFuture, Future};
+use futures::stream::{self, BoxStream, Stream, StreamExt, TryStreamExt};
+
+use crate::error::Result;
+
+/// 单页最大返回数量
+pub const MAX_PAGE_SIZE: i32 = 100;
+
+/// 使用`Offset`/`Limit`分页的请求
+pub trait PagedRequest: Clone {
+    /// 获取请求中的偏移量
+    fn get_offset(&self) -> Option<i32>;
+
+    /// 获取请求中的返回数量
+    fn get_limit(&self) -> Option<i32>;
+
+    /// 设置偏移量和返回数量
+    fn set_page(&mut self, offset: i32, limit: i32);
+}
+
+/// 包含`TotalCount`的分页响应
+pub trait PagedResponse {
+    /// 结果列表中的元素类型
+    type Item;
+
+    /// 符合条件的结果总数
+    fn total_count(&self) -> i32;
+
+    /// 取出当前页的结果列表
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// 获取一页结果的函数
+type FetchPage<'a, Req, Resp> = Box<dyn Fn(Req) -> BoxFuture<'a, Result<Resp>> + Send + Sync + 'a>;
+
+/// 分页查询器
+///
+/// 先请求第一页获取`TotalCount`，再按顺序请求剩余的页。
+/// 设置并发数后，会同时预取多页，结果仍按偏移量顺序返回。
+/// 请求出错时返回该错误并结束。
+pub struct Paginator<'a, Req, Resp> {
+    request: Req,
+    fetch: FetchPage<'a, Req, Resp>,
+    page_size: i32,
+    concurrency: usize,
+}
+
+impl<'a, Req, Resp> Paginator<'a, Req, Resp>
+where
+    Req: PagedRequest + Send + Sync + 'a,
+    Resp: PagedResponse + Send + 'a,
+    Resp::Item: Send + 'a,
+{
+    /// 创建分页查询器
+    ///
+    /// 请求中的`Offset`作为起始偏移量，`Limit`作为每页数量（默认为100）
+    pub fn new<F, Fut>(request: Req, fetch: F) -> Self
+    where
+        F: Fn(Req) -> Fut + Send + Sync + 'a,
+        Fut: Future<Output = Result<Resp>> + Send + 'a,
+    {
+        let page_size = request.get_limit().unwrap_or(MAX_PAGE_SIZE);
+        Self {
+            request,
+            fetch: Box::new(move |request| Box::pin(fetch(request))),
+            page_size: page_size.clamp(1, MAX_PAGE_SIZE),
+            concurrency: 1,
+        }
+    }
+
+    /// 设置每页数量，取值范围为1到100
+    pub fn page_size(mut self, page_size: i32) -> Self {
+        self.page_size = page_size.clamp(1, MAX_PAGE_SIZE);
+        self
+    }
+
+    /// 设置同时请求的页数，默认为1，即逐页请求
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// 按页返回查询结果
+    pub fn pages(self) -> impl Stream<Item = Result<Resp>> + Send + 'a {
+        let Paginator { request, fetch, page_size, concurrency } = self;
+        let start = request.get_offset().unwrap_or(0);
+
+        let mut first_request = request.clone();
+        first_request.set_page(start, page_size);
+        let first_page = fetch(first_request);
+
+        stream::once(async move {
+            let page = match first_page.await {
+                Ok(page) => page,
+                Err(err) => return stream::iter([Err(err)]).boxed(),
+            };
+
+            // 根据第一页返回的总数计算剩余各页的偏移量
+            let offsets = (start.saturating_add(page_size)..page.total_count()).step_by(page_size as usize);
+            let rest = stream::iter(offsets)
+                .map(move |offset| {
+                    let mut request = request.clone();
+                    request.set_page(offset, page_size);
+                    fetch(request)
+                })
+                .buffered(concurrency);
+            let pages: BoxStream<'a, Result<Resp>> = stream::once(future::ready(Ok(page))).chain(rest).boxed();
+            pages
+        })
+        .flatten()
+        // 出错后不再返回后续的页
+        .scan(false, |failed, page| {
+            if *failed {
+                return future::ready(None);
+            }
+            *failed = page.is_err();
+            future::ready(Some(page))
+        })
+    }
+
+    /// 逐条返回查询结果
+    pub fn items(self) -> impl Stream<Item = Result<Resp::Item>> + Send + 'a {
+        self.pages()
+            .map_ok(|page| stream::iter(page.into_items().into_iter().map(Ok)))
+            .try_flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, Default)]
+    struct NumbersRequest {
+        Offset: Option<i32>,
+        Limit: Option<i32>,
+    }
+
+    impl PagedRequest for NumbersRequest {
+        fn get_offset(&self) -> Option<i32> {
+            self.Offset
+        }
+
+        fn get_limit(&self) -> Option<i32> {
+            self.Limit
+        }
+
+        fn set_page(&mut self, offset: i32, limit: i32) {
+            self.Offset = Some(offset);
+            self.Limit = Some(limit);
+        }
+    }
+
+    struct NumbersResponse {
+        TotalCount: i32,
+        Numbers: Vec<i32>,
+    }
+
+    impl PagedResponse for NumbersResponse {
+        type Item = i32;
+
+        fn total_count(&self) -> i32 {
+            self.TotalCount
+        }
+
+        fn into_items(self) -> Vec<i32> {
+            self.Numbers
+        }
+    }
+
+    /// 模拟共有`total`条结果的查询接口，记录每次请求的偏移量
+    fn numbers(
+        total: i32,
+        offsets: Arc<Mutex<Vec<i32>>>,
+    ) -> impl Fn(NumbersRequest) -> BoxFuture<'static, Result<NumbersResponse>> + Send + Sync {
+        move |request| {
+            let offsets = offsets.clone();
+            Box::pin(async move {
+                let offset = request.Offset.unwrap();
+                let limit = request.Limit.unwrap();
+                offsets.lock().unwrap().push(offset);
+                // 让后面的页先完成，验证结果仍按顺序返回
+                tokio::time::sleep(std::time::Duration::from_millis((10 - offset / 100).max(0) as u64)).await;
+                if offset == 666 {
+                    return Err(Error::Other("broken page".to_string()));
+                }
+                Ok(NumbersResponse {
+                    TotalCount: total,
+                    Numbers: (offset..(offset + limit).min(total)).collect(),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_items_walks_all_pages() {
+        let offsets = Arc::new(Mutex::new(Vec::new()));
+        let items: Vec<i32> = Paginator::new(NumbersRequest::default(), numbers(250, offsets.clone()))
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(items, (0..250).collect::<Vec<_>>());
+        assert_eq!(*offsets.lock().unwrap(), vec![0, 100, 200]);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_prefetch_keeps_order() {
+        let offsets = Arc::new(Mutex::new(Vec::new()));
+        let request = NumbersRequest { Offset: Some(5), Limit: Some(30) };
+        let items: Vec<i32> = Paginator::new(request, numbers(305, offsets.clone()))
+            .concurrency(4)
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(items, (5..305).collect::<Vec<_>>());
+        assert_eq!(offsets.lock().unwrap().len(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_stops_after_error() {
+        let offsets = Arc::new(Mutex::new(Vec::new()));
+        let request = NumbersRequest { Offset: Some(66), Limit: Some(100) };
+        let pages: Vec<Result<NumbersResponse>> = Paginator::new(request, numbers(1000, offsets))
+            .pages()
+            .collect()
+            .await;
+        assert_eq!(pages.len(), 7);
+        assert!(pages[..6].iter().all(|page| page.is_ok()));
+        assert!(pages[6].is_err());
+    }
+}
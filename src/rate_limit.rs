@@ -0,0 +1,222 @@
+//! 客户端限流
+//!
+//! 基于令牌桶算法，按服务或接口限制请求频率，避免触发服务端的`RequestLimitExceeded`
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+
+/// 令牌桶
+#[derive(Debug)]
+struct TokenBucket {
+    /// 每秒生成的令牌数
+    rate: f64,
+    /// 桶容量，即允许的突发请求数
+    capacity: f64,
+    /// 当前令牌数，为负数时表示已预约的令牌
+    tokens: f64,
+    /// 上次更新时间
+    last: Instant,
+}
+
+impl TokenBucket {
+    fn new(limit: RateLimit, now: Instant) -> Self {
+        Self {
+            rate: limit.qps,
+            capacity: limit.burst as f64,
+            tokens: limit.burst as f64,
+            last: now,
+        }
+    }
+
+    /// 预约一个令牌，返回需要等待的时间
+    fn reserve(&mut self, now: Instant) -> Duration {
+        let elapsed = now.saturating_duration_since(self.last).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.last = now;
+
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.rate)
+        }
+    }
+}
+
+/// 限流规则
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimit {
+    /// 每秒允许的请求数
+    qps: f64,
+    /// 允许的突发请求数
+    burst: u32,
+}
+
+impl RateLimit {
+    /// 创建限流规则，突发请求数默认等于每秒请求数
+    ///
+    /// `qps`不是大于0的有限数时返回`Error::ParameterError`
+    pub fn new(qps: f64) -> Result<Self> {
+        if !(qps.is_finite() && qps > 0.0) {
+            return Err(Error::ParameterError(format!("每秒请求数必须是大于0的有限数，当前为{}", qps)));
+        }
+        Ok(Self {
+            qps,
+            burst: (qps.ceil() as u32).max(1),
+        })
+    }
+
+    /// 设置允许的突发请求数
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    /// 获取每秒允许的请求数
+    pub fn get_qps(&self) -> f64 {
+        self.qps
+    }
+
+    /// 获取允许的突发请求数
+    pub fn get_burst(&self) -> u32 {
+        self.burst
+    }
+}
+
+/// 令牌桶限流器
+///
+/// 规则按 接口 -> 服务 -> 默认 的优先级匹配，没有匹配到规则的请求不限流。
+/// 克隆的限流器共享令牌桶状态。
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    /// 按接口配置的规则，键为`服务/接口`
+    action_limits: HashMap<String, RateLimit>,
+    /// 按服务配置的规则
+    service_limits: HashMap<String, RateLimit>,
+    /// 默认规则
+    default_limit: Option<RateLimit>,
+    /// 令牌桶状态
+    buckets: Arc<Mutex<HashMap<String, TokenBucket>>>,
+}
+
+impl RateLimiter {
+    /// 创建新的限流器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 为指定接口设置限流规则，如`limit("cvm", "DescribeInstances", RateLimit::new(40.0)?)`
+    pub fn limit(mut self, service: impl Into<String>, action: impl Into<String>, limit: RateLimit) -> Self {
+        self.action_limits
+            .insert(format!("{}/{}", service.into(), action.into()), limit);
+        self
+    }
+
+    /// 为指定服务的所有接口设置限流规则，每个接口分别计数
+    pub fn service_limit(mut self, service: impl Into<String>, limit: RateLimit) -> Self {
+        self.service_limits.insert(service.into(), limit);
+        self
+    }
+
+    /// 设置默认限流规则，每个接口分别计数
+    pub fn default_limit(mut self, limit: RateLimit) -> Self {
+        self.default_limit = Some(limit);
+        self
+    }
+
+    /// 查找接口对应的限流规则
+    fn find_limit(&self, key: &str, service: &str) -> Option<RateLimit> {
+        self.action_limits
+            .get(key)
+            .or_else(|| self.service_limits.get(service))
+            .or(self.default_limit.as_ref())
+            .copied()
+    }
+
+    /// 预约一个令牌，返回需要等待的时间
+    fn reserve(&self, service: &str, action: &str, now: Instant) -> Duration {
+        let key = format!("{}/{}", service, action);
+        let limit = match self.find_limit(&key, service) {
+            Some(limit) => limit,
+            None => return Duration::ZERO,
+        };
+
+        let mut buckets = self.buckets.lock().expect("限流器状态锁异常");
+        buckets
+            .entry(key)
+            .or_insert_with(|| TokenBucket::new(limit, now))
+            .reserve(now)
+    }
+
+    /// 等待直到获取到令牌
+    pub async fn acquire(&self, service: &str, action: &str) {
+        let wait = self.reserve(service, action, Instant::now());
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_allows_burst_then_waits() {
+        let limiter = RateLimiter::new().limit("cvm", "DescribeInstances", RateLimit::new(10.0).unwrap().burst(2));
+        let now = Instant::now();
+        assert_eq!(limiter.reserve("cvm", "DescribeInstances", now), Duration::ZERO);
+        assert_eq!(limiter.reserve("cvm", "DescribeInstances", now), Duration::ZERO);
+        assert_eq!(limiter.reserve("cvm", "DescribeInstances", now), Duration::from_millis(100));
+        assert_eq!(limiter.reserve("cvm", "DescribeInstances", now), Duration::from_millis(200));
+
+        // 令牌随时间恢复
+        let later = now + Duration::from_secs(1);
+        assert_eq!(limiter.reserve("cvm", "DescribeInstances", later), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_rule_priority() {
+        let limiter = RateLimiter::new()
+            .service_limit("cvm", RateLimit::new(1.0).unwrap())
+            .limit("cvm", "DescribeInstances", RateLimit::new(100.0).unwrap());
+        let now = Instant::now();
+
+        // 接口规则优先于服务规则
+        for _ in 0..100 {
+            assert_eq!(limiter.reserve("cvm", "DescribeInstances", now), Duration::ZERO);
+        }
+
+        // 服务规则按接口分别计数
+        assert_eq!(limiter.reserve("cvm", "StartInstances", now), Duration::ZERO);
+        assert_eq!(limiter.reserve("cvm", "StopInstances", now), Duration::ZERO);
+        assert!(limiter.reserve("cvm", "StartInstances", now) > Duration::ZERO);
+
+        // 未配置规则的服务不限流
+        for _ in 0..10 {
+            assert_eq!(limiter.reserve("sts", "AssumeRole", now), Duration::ZERO);
+        }
+    }
+
+    #[test]
+    fn test_rejects_invalid_qps() {
+        for qps in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(RateLimit::new(qps), Err(Error::ParameterError(_))), "qps = {}", qps);
+        }
+        let limit = RateLimit::new(0.5).unwrap();
+        assert_eq!((limit.get_qps(), limit.get_burst()), (0.5, 1));
+    }
+
+    #[tokio::test]
+    async fn test_acquire_waits_for_token() {
+        let limiter = RateLimiter::new().default_limit(RateLimit::new(50.0).unwrap().burst(1));
+        let start = Instant::now();
+        for _ in 0..4 {
+            limiter.acquire("cvm", "DescribeInstances").await;
+        }
+        assert!(start.elapsed() >= Duration::from_millis(55));
+    }
+}
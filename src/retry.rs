@@ -0,0 +1,219 @@
+//! 请求重试策略
+//!
+//! 对连接错误、5xx响应和可重试的API错误码按指数退避加随机抖动进行重试
+//!
+//! 连接错误、超时、5xx响应和`InternalError`时请求可能已经在服务端执行，这些错误只对幂等接口或携带
+//! `ClientToken`的请求重试，避免重试`RunInstances`等接口时重复创建资源或重复计费
+
+use rand::Rng;
+use std::time::Duration;
+
+use crate::error::Error;
+
+/// 默认的可重试API错误码，同时匹配以这些错误码为前缀的子错误码
+pub const DEFAULT_RETRYABLE_CODES: &[&str] = &["RequestLimitExceeded", "InternalError", "ResourceBusy"];
+
+/// 视为幂等的接口名称前缀，这些只读接口在请求可能已执行的错误后也可以重试
+pub const DEFAULT_IDEMPOTENT_PREFIXES: &[&str] = &["Describe", "Inquiry"];
+
+/// 表示请求在执行前被拒绝的错误码，非幂等请求遇到这些错误码时也可以重试
+const REJECTED_CODES: &[&str] = &["RequestLimitExceeded", "ResourceBusy"];
+
+/// 请求重试策略
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// 最大重试次数，不包含首次请求
+    max_retries: u32,
+    /// 首次重试的基础等待时间
+    base_delay: Duration,
+    /// 单次等待时间上限
+    max_delay: Duration,
+    /// 可重试的API错误码
+    retryable_codes: Vec<String>,
+    /// 额外视为幂等的接口名称
+    idempotent_actions: Vec<String>,
+}
+
+impl Default for RetryPolicy {
+    /// 默认策略：最多重试3次，基础等待200毫秒，单次最多等待5秒
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            retryable_codes: DEFAULT_RETRYABLE_CODES.iter().map(|c| c.to_string()).collect(),
+            idempotent_actions: Vec::new(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// 创建默认的重试策略
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 不进行重试
+    pub fn none() -> Self {
+        Self::default().max_retries(0)
+    }
+
+    /// 设置最大重试次数
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// 设置首次重试的基础等待时间
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// 设置单次等待时间上限
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// 追加可重试的API错误码
+    pub fn retryable_code(mut self, code: impl Into<String>) -> Self {
+        self.retryable_codes.push(code.into());
+        self
+    }
+
+    /// 追加视为幂等的接口名称，该接口在连接错误和超时后也会重试
+    ///
+    /// 只应添加重复执行不会产生副作用的接口
+    ///
+    /// `GetFederationToken`等每次调用都会签发新凭证的接口默认不视为幂等
+    pub fn idempotent_action(mut self, action: impl Into<String>) -> Self {
+        self.idempotent_actions.push(action.into());
+        self
+    }
+
+    /// 获取最大重试次数
+    pub fn get_max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// 判断错误是否可以重试
+    pub fn is_retryable(&self, error: &Error) -> bool {
+        match error {
+            Error::ApiError { code, .. } => matches_code(&code.to_string(), &self.retryable_codes),
+            _ => error.is_retryable(),
+        }
+    }
+
+    /// 判断接口是否幂等：名称以`Describe`、`Inquiry`开头或通过`idempotent_action`添加
+    pub fn is_idempotent(&self, action: &str) -> bool {
+        DEFAULT_IDEMPOTENT_PREFIXES.iter().any(|prefix| action.starts_with(prefix))
+            || self.idempotent_actions.iter().any(|idempotent| idempotent == action)
+    }
+
+    /// 判断请求失败后是否应该重试
+    ///
+    /// `idempotent`为`false`（非幂等接口且未携带`ClientToken`）时，只重试`RequestLimitExceeded`和
+    /// `ResourceBusy`等表示请求未执行的错误码；连接错误、超时、5xx响应和`InternalError`时请求可能已经执行，不重试
+    pub fn should_retry(&self, error: &Error, idempotent: bool) -> bool {
+        if !self.is_retryable(error) {
+            return false;
+        }
+        match error {
+            _ if idempotent => true,
+            Error::ApiError { code, .. } => matches_code(&code.to_string(), REJECTED_CODES),
+            _ => false,
+        }
+    }
+
+    /// 计算第`attempt`次重试（从0开始）前的等待时间
+    ///
+    /// 等待时间为 `[0, min(max_delay, base_delay * 2^attempt)]` 区间内的随机值
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exponential = self.base_delay.saturating_mul(2u32.saturating_pow(attempt));
+        let cap = exponential.min(self.max_delay);
+        let millis = cap.as_millis() as u64;
+        if millis == 0 {
+            return Duration::ZERO;
+        }
+        Duration::from_millis(rand::rng().random_range(0..=millis))
+    }
+}
+
+/// 判断错误码是否等于列表中的错误码或为其子错误码
+fn matches_code<S: AsRef<str>>(code: &str, codes: &[S]) -> bool {
+    codes.iter().any(|candidate| {
+        let candidate = candidate.as_ref();
+        code == candidate || code.strip_prefix(candidate).is_some_and(|rest| rest.starts_with('.'))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api_error(code: &str) -> Error {
+        Error::ApiError {
+            code: code.into(),
+            message: String::new(),
+            request_id: String::new(),
+            status: 200,
+        }
+    }
+
+    #[test]
+    fn test_retryable_codes() {
+        let policy = RetryPolicy::new();
+        assert!(policy.is_retryable(&api_error("RequestLimitExceeded")));
+        assert!(policy.is_retryable(&api_error("InternalError.DbError")));
+        assert!(policy.is_retryable(&api_error("ResourceBusy")));
+        assert!(!policy.is_retryable(&api_error("InternalErrorX")));
+        assert!(!policy.is_retryable(&api_error("AuthFailure.SignatureExpire")));
+        assert!(policy.retryable_code("ResourceInsufficient").is_retryable(&api_error("ResourceInsufficient")));
+    }
+
+    #[test]
+    fn test_retryable_http_status() {
+        let policy = RetryPolicy::new();
+        let error = |status| Error::HttpStatusError { status, message: String::new() };
+        assert!(policy.is_retryable(&error(502)));
+        assert!(!policy.is_retryable(&error(404)));
+    }
+
+    #[test]
+    fn test_idempotent_actions() {
+        let policy = RetryPolicy::new();
+        assert!(policy.is_idempotent("DescribeInstances"));
+        assert!(policy.is_idempotent("InquiryPriceRunInstances"));
+        assert!(!policy.is_idempotent("RunInstances"));
+        assert!(!policy.is_idempotent("GetFederationToken"));
+        assert!(policy.idempotent_action("StartInstances").is_idempotent("StartInstances"));
+    }
+
+    #[test]
+    fn test_should_retry_non_idempotent() {
+        let policy = RetryPolicy::new();
+        let bad_gateway = Error::HttpStatusError { status: 502, message: String::new() };
+        assert!(!policy.should_retry(&bad_gateway, false));
+        assert!(policy.should_retry(&bad_gateway, true));
+        assert!(!policy.should_retry(&api_error("InternalError"), false));
+        assert!(!policy.should_retry(&api_error("InternalError.DbError"), false));
+        assert!(policy.should_retry(&api_error("InternalError"), true));
+
+        // 请求在执行前被拒绝，非幂等请求也可以重试
+        assert!(policy.should_retry(&api_error("RequestLimitExceeded"), false));
+        assert!(policy.should_retry(&api_error("ResourceBusy"), false));
+        assert!(!policy.should_retry(&api_error("AuthFailure"), true));
+    }
+
+    #[test]
+    fn test_delay_is_capped() {
+        let policy = RetryPolicy::new()
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(300));
+        for attempt in 0..10 {
+            assert!(policy.delay(attempt) <= Duration::from_millis(300));
+        }
+        assert!(policy.delay(0) <= Duration::from_millis(100));
+    }
+}
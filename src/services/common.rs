@@ -0,0 +1,142 @@
+//! 公共数据结构
+//!
+//! 各服务共用的响应结构、错误结构、过滤条件、标签和位置信息
+
+use serde::{Deserialize, Serialize};
+
+/// API响应通用结构
+#[derive(Debug, Deserialize)]
+pub struct ApiResponse<T> {
+    /// 响应数据
+    pub Response: T,
+}
+
+/// API错误响应
+#[derive(Debug, Deserialize)]
+pub struct ErrorResponse {
+    /// 错误信息
+    pub Error: ApiError,
+    /// 请求ID
+    pub RequestId: String,
+}
+
+/// API错误
+#[derive(Debug, Deserialize)]
+pub struct ApiError {
+    /// 错误代码
+    pub Code: String,
+    /// 错误消息
+    pub Message: String,
+}
+
+/// 错误响应类型
+pub type ErrorResponseType = ApiResponse<ErrorResponse>;
+
+/// 过滤条件
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Filter {
+    /// 过滤键的名称
+    pub Name: String,
+
+    /// 过滤值列表
+    pub Values: Vec<String>,
+}
+
+impl Filter {
+    /// 创建过滤条件，如`Filter::new("zone", ["ap-guangzhou-3"])`
+    pub fn new<I, S>(name: impl Into<String>, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            Name: name.into(),
+            Values: values.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// 标签
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Tag {
+    /// 标签键
+    pub Key: String,
+
+    /// 标签值
+    pub Value: String,
+}
+
+impl Tag {
+    /// 创建标签
+    pub fn new(key: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            Key: key.into(),
+            Value: value.into(),
+        }
+    }
+}
+
+/// 描述了实例的位置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Placement {
+    /// 实例所属的可用区名称。该参数可以通过调用 DescribeZones 的返回值中的Zone字段来获取。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub Zone: Option<String>,
+
+    /// 实例所属项目ID。该参数可以通过调用 DescribeProjects 的返回值中的 projectId 字段来获取。
+    /// 不填为默认项目。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ProjectId: Option<i32>,
+
+    /// 实例所属的专用宿主机ID列表，仅用于入参。如果您有购买专用宿主机并且指定了该参数，则您购买的实例就会随机的部署在这些专用宿主机上。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub HostIds: Option<Vec<String>>,
+
+    /// 指定母机机型，默认值为：S5
+    ///
+    /// 全局母机：S1，S2，S3，S4，S5，S6，S4.LARGE16，S4.LARGE32，S4.LARGE48
+    /// FPGA母机：SF.V10001
+    ///
+    /// 各个地域的主机类型一般情况下不同，详情参考控制台。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub HostIps: Option<Vec<String>>,
+
+    /// 指定专用集群ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub DedicatedClusterId: Option<String>,
+}
+
+impl Placement {
+    /// 创建指定可用区的位置信息
+    pub fn zone(zone: impl Into<String>) -> Self {
+        Self {
+            Zone: Some(zone.into()),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_new() {
+        let filter = Filter::new("zone", ["ap-guangzhou-3"]);
+        assert_eq!(
+            serde_json::to_string(&filter).unwrap(),
+            r#"{"Name":"zone","Values":["ap-guangzhou-3"]}"#
+        );
+        assert_eq!(Filter::new("instance-id", vec!["ins-1".to_string()]).Values, ["ins-1"]);
+    }
+
+    #[test]
+    fn test_error_envelope() {
+        let response: ErrorResponseType = serde_json::from_str(
+            r#"{"Response":{"Error":{"Code":"InvalidParameter","Message":"bad"},"RequestId":"req-1"}}"#,
+        )
+        .unwrap();
+        assert_eq!(response.Response.Error.Code, "InvalidParameter");
+        assert_eq!(response.Response.RequestId, "req-1");
+    }
+}
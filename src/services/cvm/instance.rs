@@ -4,27 +4,66 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::action::impl_action;
 use crate::client::TencentCloudClient;
-use crate::error::Result;
-
-/// 实例计费类型
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum InstanceChargeType {
-    /// 预付费，即包年包月
-    Prepaid,
-    /// 按小时后付费
-    PostpaidByHour,
-    /// 独享子机（基于专用宿主机创建，宿主机部分的资源不收费）
-    Cdhpaid,
-    /// 竞价付费
-    Spotpaid,
-    /// 专用集群付费
-    Cdcpaid,
+use crate::error::{Error, Result};
+pub use crate::services::common::{ApiResponse, Filter, Placement, Tag};
+use crate::utils::string_enum::string_enum;
+
+string_enum! {
+    /// 实例计费类型
+    pub enum InstanceChargeType {
+        /// 预付费，即包年包月
+        Prepaid = "PREPAID",
+        /// 按小时后付费
+        PostpaidByHour = "POSTPAID_BY_HOUR",
+        /// 独享子机（基于专用宿主机创建，宿主机部分的资源不收费）
+        Cdhpaid = "CDHPAID",
+        /// 竞价付费
+        Spotpaid = "SPOTPAID",
+        /// 专用集群付费
+        Cdcpaid = "CDCPAID",
+    }
+}
+
+string_enum! {
+    /// 实例状态
+    pub enum InstanceState {
+        /// 创建中
+        Pending = "PENDING",
+        /// 创建失败
+        LaunchFailed = "LAUNCH_FAILED",
+        /// 运行中
+        Running = "RUNNING",
+        /// 关机
+        Stopped = "STOPPED",
+        /// 开机中
+        Starting = "STARTING",
+        /// 关机中
+        Stopping = "STOPPING",
+        /// 重启中
+        Rebooting = "REBOOTING",
+        /// 停止待销毁
+        Shutdown = "SHUTDOWN",
+        /// 销毁中
+        Terminating = "TERMINATING",
+    }
+}
+
+string_enum! {
+    /// 自动续费标识
+    pub enum RenewFlag {
+        /// 通知过期且自动续费
+        NotifyAndAutoRenew = "NOTIFY_AND_AUTO_RENEW",
+        /// 通知过期不自动续费
+        NotifyAndManualRenew = "NOTIFY_AND_MANUAL_RENEW",
+        /// 不通知过期不自动续费
+        DisableNotifyAndManualRenew = "DISABLE_NOTIFY_AND_MANUAL_RENEW",
+    }
 }
 
 /// 预付费模式，即包年包月相关参数设置
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct InstanceChargePrepaid {
     /// 购买实例的时长，单位：月。取值范围：1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 24, 36, 48, 60。
     pub Period: i32,
@@ -36,41 +75,11 @@ pub struct InstanceChargePrepaid {
     /// 
     /// 默认取值：NOTIFY_AND_MANUAL_RENEW。若该参数指定为NOTIFY_AND_AUTO_RENEW，在账户余额充足的情况下，实例到期后将按月自动续费。
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub RenewFlag: Option<String>,
-}
-
-/// 描述了实例的位置
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Placement {
-    /// 实例所属的可用区名称。该参数可以通过调用 DescribeZones 的返回值中的Zone字段来获取。
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub Zone: Option<String>,
-
-    /// 实例所属项目ID。该参数可以通过调用 DescribeProjects 的返回值中的 projectId 字段来获取。
-    /// 不填为默认项目。
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub ProjectId: Option<i32>,
-
-    /// 实例所属的专用宿主机ID列表，仅用于入参。如果您有购买专用宿主机并且指定了该参数，则您购买的实例就会随机的部署在这些专用宿主机上。
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub HostIds: Option<Vec<String>>,
-
-    /// 指定母机机型，默认值为：S5
-    /// 
-    /// 全局母机：S1，S2，S3，S4，S5，S6，S4.LARGE16，S4.LARGE32，S4.LARGE48
-    /// FPGA母机：SF.V10001
-    /// 
-    /// 各个地域的主机类型一般情况下不同，详情参考控制台。
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub HostIps: Option<Vec<String>>,
-
-    /// 指定专用集群ID
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub DedicatedClusterId: Option<String>,
+    pub RenewFlag: Option<RenewFlag>,
 }
 
 /// 描述了操作系统所在块设备即系统盘的信息
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SystemDisk {
     /// 系统盘类型。系统盘类型限制详见存储概述。取值范围：
     /// LOCAL_BASIC：本地硬盘
@@ -96,7 +105,7 @@ pub struct SystemDisk {
 }
 
 /// 描述了数据盘的信息
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DataDisk {
     /// 数据盘类型。数据盘类型限制详见存储概述。取值范围：
     /// LOCAL_BASIC：本地硬盘
@@ -137,7 +146,7 @@ pub struct DataDisk {
 }
 
 /// 描述了VPC相关信息，包括子网，私有IP
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct VirtualPrivateCloud {
     /// 私有网络ID，形如vpc-xxx。有效的VpcId可通过登录控制台查询；也可以调用接口 DescribeVpcEx ，从接口返回中的unVpcId字段获取。
     /// 若在创建子机时VpcId与SubnetId同时传入SubnetId将自动忽略。通过指定该参数可以指定私有网络的子网IP。
@@ -161,7 +170,7 @@ pub struct VirtualPrivateCloud {
 }
 
 /// 描述了实例的公网可访问性，声明了实例的公网使用计费模式，最大带宽等
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct InternetAccessible {
     /// 网络计费类型。取值范围：
     /// BANDWIDTH_PREPAID：预付费按带宽结算
@@ -191,7 +200,7 @@ pub struct InternetAccessible {
 }
 
 /// 描述了实例登录相关配置与信息
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct LoginSettings {
     /// 实例登录密码。不同操作系统类型密码复杂度限制不一样，具体如下：
     /// Linux实例密码必须8到30位，至少包括两项[a-z]，[A-Z]、[0-9] 和 [( ) ` ~ ! @ # $ % ^ & * - + = | { } [ ] : ; ' , . ? / ]中的特殊符号。
@@ -225,7 +234,7 @@ pub struct LoginSettings {
 }
 
 /// 描述了实例的增强服务启用情况与其设置
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EnhancedService {
     /// 开启云安全服务。若不指定该参数，则默认开启云安全服务。
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -241,7 +250,7 @@ pub struct EnhancedService {
 }
 
 /// 描述了实例的云监控服务
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct RunMonitorServiceEnabled {
     /// 是否开启云监控服务。取值范围：
     /// TRUE：表示开启云监控服务
@@ -252,7 +261,7 @@ pub struct RunMonitorServiceEnabled {
 }
 
 /// 描述了实例的安全服务
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct RunSecurityServiceEnabled {
     /// 是否开启云安全服务。取值范围：
     /// TRUE：表示开启云安全服务
@@ -263,7 +272,7 @@ pub struct RunSecurityServiceEnabled {
 }
 
 /// 描述了实例的TAT服务
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct RunAutomationServiceEnabled {
     /// 是否开启TAT服务。取值范围：
     /// TRUE：表示开启云自动化助手服务
@@ -274,7 +283,7 @@ pub struct RunAutomationServiceEnabled {
 }
 
 /// 描述了标签对
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TagSpecification {
     /// 标签绑定的资源类型，当前支持类型："instance"、"host"、"image"、"snapshot"、"disk"、"vpc"、"subnet"、"security-group"、"region-route-table"
     pub ResourceType: String,
@@ -283,18 +292,8 @@ pub struct TagSpecification {
     pub Tags: Vec<Tag>,
 }
 
-/// 标签
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Tag {
-    /// 标签键
-    pub Key: String,
-
-    /// 标签值
-    pub Value: String,
-}
-
 /// 定时任务
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ActionTimer {
     /// 定时器动作，目前仅支持销毁云主机
     /// 取值范围：TerminateInstances
@@ -305,7 +304,7 @@ pub struct ActionTimer {
 }
 
 /// CPU拓扑结构
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CpuTopology {
     /// 每个核心的线程数
     pub ThreadsPerCore: i32,
@@ -315,7 +314,7 @@ pub struct CpuTopology {
 }
 
 /// 实例市场相关选项
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct InstanceMarketOptionsRequest {
     /// 竞价相关选项
     pub SpotOptions: SpotMarketOptions,
@@ -325,7 +324,7 @@ pub struct InstanceMarketOptionsRequest {
 }
 
 /// 竞价相关选项
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SpotMarketOptions {
     /// 竞价出价，只需要关注SpotMaxPrice参数
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -345,7 +344,7 @@ pub struct SpotMarketOptions {
 }
 
 /// 实例启动模板
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct LaunchTemplate {
     /// 实例启动模板ID，通过DescribeLaunchTemplates查询
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -357,6 +356,8 @@ pub struct LaunchTemplate {
 }
 
 /// 创建实例的请求参数
+///
+/// 不实现`Default`，通过`RunInstancesRequest::builder()`创建，`build()`时校验必填参数
 #[derive(Debug, Clone, Serialize)]
 pub struct RunInstancesRequest {
     /// 实例所在的位置。通过该参数可以指定实例所属可用区，所属项目，所属宿主机（在专用宿主机上创建子机时指定）等属性。
@@ -495,6 +496,336 @@ pub struct RunInstancesRequest {
     pub DisableApiTermination: Option<bool>,
 }
 
+impl RunInstancesRequest {
+    /// 创建构造器
+    pub fn builder() -> RunInstancesRequestBuilder {
+        RunInstancesRequestBuilder::default()
+    }
+}
+
+/// 创建实例请求参数构造器
+#[derive(Debug, Clone)]
+pub struct RunInstancesRequestBuilder {
+    request: RunInstancesRequest,
+}
+
+impl Default for RunInstancesRequestBuilder {
+    fn default() -> Self {
+        let request = RunInstancesRequest {
+            Placement: None,
+            ImageId: None,
+            InstanceChargeType: None,
+            InstanceChargePrepaid: None,
+            InstanceType: None,
+            SystemDisk: None,
+            DataDisks: None,
+            VirtualPrivateCloud: None,
+            InternetAccessible: None,
+            InstanceCount: None,
+            InstanceName: None,
+            LoginSettings: None,
+            SecurityGroupIds: None,
+            EnhancedService: None,
+            ClientToken: None,
+            HostName: None,
+            TagSpecification: None,
+            ProjectId: None,
+            UserData: None,
+            ActionTimer: None,
+            DisasterRecoverGroupIds: None,
+            InstanceMarketOptions: None,
+            DryRun: None,
+            CpuTopology: None,
+            CamRoleName: None,
+            HpcClusterId: None,
+            LaunchTemplate: None,
+            DedicatedClusterId: None,
+            ChcIds: None,
+            DisableApiTermination: None,
+        };
+        Self { request }
+    }
+}
+
+impl RunInstancesRequestBuilder {
+    /// 设置实例所在的可用区，如`ap-guangzhou-6`
+    pub fn zone(mut self, zone: impl Into<String>) -> Self {
+        self.request.Placement.get_or_insert_with(Placement::default).Zone = Some(zone.into());
+        self
+    }
+
+    /// 设置实例所在的位置，覆盖已设置的可用区和项目ID
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.request.Placement = Some(placement);
+        self
+    }
+
+    /// 设置实例所属项目ID
+    pub fn project_id(mut self, project_id: i32) -> Self {
+        self.request.Placement.get_or_insert_with(Placement::default).ProjectId = Some(project_id);
+        self
+    }
+
+    /// 设置镜像ID，如`img-6n21msk1`
+    pub fn image_id(mut self, image_id: impl Into<String>) -> Self {
+        self.request.ImageId = Some(image_id.into());
+        self
+    }
+
+    /// 设置实例机型，如`S5.MEDIUM2`
+    pub fn instance_type(mut self, instance_type: impl Into<String>) -> Self {
+        self.request.InstanceType = Some(instance_type.into());
+        self
+    }
+
+    /// 设置实例计费类型
+    pub fn instance_charge_type(mut self, instance_charge_type: InstanceChargeType) -> Self {
+        self.request.InstanceChargeType = Some(instance_charge_type);
+        self
+    }
+
+    /// 使用包年包月计费，设置购买时长（月）和自动续费标识
+    pub fn prepaid(mut self, period: i32, renew_flag: RenewFlag) -> Self {
+        self.request.InstanceChargeType = Some(InstanceChargeType::Prepaid);
+        self.request.InstanceChargePrepaid = Some(InstanceChargePrepaid {
+            Period: period,
+            RenewFlag: Some(renew_flag),
+        });
+        self
+    }
+
+    /// 设置系统盘类型和大小（GB）
+    pub fn system_disk(mut self, disk_type: impl Into<String>, disk_size: i32) -> Self {
+        self.request.SystemDisk = Some(SystemDisk {
+            DiskType: Some(disk_type.into()),
+            DiskSize: Some(disk_size),
+            DiskId: None,
+        });
+        self
+    }
+
+    /// 添加一块数据盘
+    pub fn data_disk(mut self, data_disk: DataDisk) -> Self {
+        self.request.DataDisks.get_or_insert_with(Vec::new).push(data_disk);
+        self
+    }
+
+    /// 设置私有网络配置，如指定私有IP或分配IPv6地址
+    pub fn virtual_private_cloud(mut self, virtual_private_cloud: VirtualPrivateCloud) -> Self {
+        self.request.VirtualPrivateCloud = Some(virtual_private_cloud);
+        self
+    }
+
+    /// 设置私有网络和子网
+    pub fn vpc(mut self, vpc_id: impl Into<String>, subnet_id: impl Into<String>) -> Self {
+        self.request.VirtualPrivateCloud = Some(VirtualPrivateCloud {
+            VpcId: vpc_id.into(),
+            SubnetId: subnet_id.into(),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// 分配公网IP，设置网络计费类型和公网出带宽上限（Mbps）
+    pub fn public_ip(mut self, internet_charge_type: impl Into<String>, bandwidth_out: i32) -> Self {
+        self.request.InternetAccessible = Some(InternetAccessible {
+            InternetChargeType: internet_charge_type.into(),
+            InternetMaxBandwidthOut: bandwidth_out,
+            PublicIpAssigned: Some(true),
+            BandwidthPackageId: None,
+        });
+        self
+    }
+
+    /// 设置公网带宽配置，如使用带宽包
+    pub fn internet_accessible(mut self, internet_accessible: InternetAccessible) -> Self {
+        self.request.InternetAccessible = Some(internet_accessible);
+        self
+    }
+
+    /// 设置购买实例数量
+    pub fn instance_count(mut self, instance_count: i32) -> Self {
+        self.request.InstanceCount = Some(instance_count);
+        self
+    }
+
+    /// 设置实例显示名称
+    pub fn instance_name(mut self, instance_name: impl Into<String>) -> Self {
+        self.request.InstanceName = Some(instance_name.into());
+        self
+    }
+
+    /// 设置实例登录密码
+    pub fn password(mut self, password: impl Into<String>) -> Self {
+        self.request.LoginSettings.get_or_insert_with(LoginSettings::default).Password = Some(password.into());
+        self
+    }
+
+    /// 设置登录密钥ID列表
+    pub fn key_ids<I, S>(mut self, key_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.request.LoginSettings.get_or_insert_with(LoginSettings::default).KeyIds =
+            Some(key_ids.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// 设置实例登录配置，如保持镜像的原始登录设置
+    pub fn login_settings(mut self, login_settings: LoginSettings) -> Self {
+        self.request.LoginSettings = Some(login_settings);
+        self
+    }
+
+    /// 设置实例所属安全组
+    pub fn security_group_ids<I, S>(mut self, security_group_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.request.SecurityGroupIds = Some(security_group_ids.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// 设置增强服务
+    pub fn enhanced_service(mut self, enhanced_service: EnhancedService) -> Self {
+        self.request.EnhancedService = Some(enhanced_service);
+        self
+    }
+
+    /// 设置用于保证请求幂等性的字符串
+    pub fn client_token(mut self, client_token: impl Into<String>) -> Self {
+        self.request.ClientToken = Some(client_token.into());
+        self
+    }
+
+    /// 设置实例主机名
+    pub fn host_name(mut self, host_name: impl Into<String>) -> Self {
+        self.request.HostName = Some(host_name.into());
+        self
+    }
+
+    /// 为实例添加标签
+    pub fn tag(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        let specifications = self.request.TagSpecification.get_or_insert_with(Vec::new);
+        let tag = Tag { Key: key.into(), Value: value.into() };
+        match specifications.iter_mut().find(|s| s.ResourceType == "instance") {
+            Some(specification) => specification.Tags.push(tag),
+            None => specifications.push(TagSpecification {
+                ResourceType: "instance".to_string(),
+                Tags: vec![tag],
+            }),
+        }
+        self
+    }
+
+    /// 设置实例自定义数据，需要以Base64方式编码
+    pub fn user_data(mut self, user_data: impl Into<String>) -> Self {
+        self.request.UserData = Some(user_data.into());
+        self
+    }
+
+    /// 设置CAM角色名称
+    pub fn cam_role_name(mut self, cam_role_name: impl Into<String>) -> Self {
+        self.request.CamRoleName = Some(cam_role_name.into());
+        self
+    }
+
+    /// 设置实例启动模板
+    pub fn launch_template(mut self, launch_template: LaunchTemplate) -> Self {
+        self.request.LaunchTemplate = Some(launch_template);
+        self
+    }
+
+    /// 设置定时任务，目前仅支持定时销毁
+    pub fn action_timer(mut self, action_timer: ActionTimer) -> Self {
+        self.request.ActionTimer = Some(action_timer);
+        self
+    }
+
+    /// 设置置放群组ID，仅支持指定一个
+    pub fn disaster_recover_group_id(mut self, disaster_recover_group_id: impl Into<String>) -> Self {
+        self.request.DisasterRecoverGroupIds = Some(vec![disaster_recover_group_id.into()]);
+        self
+    }
+
+    /// 设置实例的市场相关选项，如竞价实例出价
+    pub fn instance_market_options(mut self, instance_market_options: InstanceMarketOptionsRequest) -> Self {
+        self.request.InstanceMarketOptions = Some(instance_market_options);
+        self
+    }
+
+    /// 设置实例CPU拓扑结构
+    pub fn cpu_topology(mut self, cpu_topology: CpuTopology) -> Self {
+        self.request.CpuTopology = Some(cpu_topology);
+        self
+    }
+
+    /// 设置高性能计算集群ID
+    pub fn hpc_cluster_id(mut self, hpc_cluster_id: impl Into<String>) -> Self {
+        self.request.HpcClusterId = Some(hpc_cluster_id.into());
+        self
+    }
+
+    /// 设置专用集群ID
+    pub fn dedicated_cluster_id(mut self, dedicated_cluster_id: impl Into<String>) -> Self {
+        self.request.DedicatedClusterId = Some(dedicated_cluster_id.into());
+        self
+    }
+
+    /// 设置用于创建CHC云主机的CHC物理服务器ID
+    pub fn chc_ids<I, S>(mut self, chc_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.request.ChcIds = Some(chc_ids.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// 设置是否开启实例销毁保护
+    pub fn disable_api_termination(mut self, disable_api_termination: bool) -> Self {
+        self.request.DisableApiTermination = Some(disable_api_termination);
+        self
+    }
+
+    /// 设置是否只预检此次请求
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.request.DryRun = Some(dry_run);
+        self
+    }
+
+    /// 校验参数并生成请求
+    ///
+    /// 未指定启动模板时，可用区和镜像ID为必填参数
+    pub fn build(self) -> Result<RunInstancesRequest> {
+        let request = self.request;
+        if request.LaunchTemplate.is_none() {
+            if request.Placement.as_ref().and_then(|p| p.Zone.as_ref()).is_none() {
+                return Err(Error::ParameterError("缺少必填参数Placement.Zone".to_string()));
+            }
+            if request.ImageId.is_none() {
+                return Err(Error::ParameterError("缺少必填参数ImageId".to_string()));
+            }
+        }
+        if let Some(count) = request.InstanceCount {
+            if !(1..=500).contains(&count) {
+                return Err(Error::ParameterError(format!("InstanceCount取值范围为1到500，当前为{}", count)));
+            }
+        }
+        if request.InstanceChargeType == Some(InstanceChargeType::Prepaid) && request.InstanceChargePrepaid.is_none() {
+            return Err(Error::ParameterError("包年包月实例必须指定InstanceChargePrepaid".to_string()));
+        }
+        if let Some(login) = &request.LoginSettings {
+            if login.Password.is_some() && login.KeyIds.is_some() {
+                return Err(Error::ParameterError("Password与KeyIds不能同时指定".to_string()));
+            }
+        }
+        Ok(request)
+    }
+}
+
 /// 创建实例的响应
 #[derive(Debug, Deserialize)]
 pub struct RunInstancesResponse {
@@ -505,24 +836,12 @@ pub struct RunInstancesResponse {
     pub RequestId: String,
 }
 
-/// API响应通用结构
-#[derive(Debug, Deserialize)]
-pub struct ApiResponse<T> {
-    /// 响应数据
-    pub Response: T,
-}
-
 /// 创建实例响应类型
 pub type RunInstancesResponseType = ApiResponse<RunInstancesResponse>;
 
-/// 查询实例列表的过滤条件
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Filter {
-    /// 过滤键的名称，支持各种实例相关的过滤条件
-    pub Name: String,
-    
-    /// 过滤值列表
-    pub Values: Vec<String>,
+impl_action! {
+    "cvm", "2017-03-12";
+    RunInstancesRequest => RunInstancesResponse = "RunInstances";
 }
 
 /// 实例服务
@@ -540,14 +859,7 @@ impl<'a> InstanceService<'a> {
     /// 
     /// 本接口(RunInstances)用于创建一个或多个指定配置的实例。
     pub async fn run_instances(&self, request: &RunInstancesRequest, region: &str) -> Result<RunInstancesResponseType> {
-        // 使用client发送请求
-        self.client.request(
-            "RunInstances", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
 }
 
@@ -568,7 +880,7 @@ pub struct Instance {
     /// POSTPAID_BY_HOUR：表示后付费，即按量计费
     /// CDHPAID：表示CDH付费，即只对CDH计费，不对CDH上的实例计费
     /// SPOTPAID：表示竞价实例付费
-    pub InstanceChargeType: String,
+    pub InstanceChargeType: InstanceChargeType,
     
     /// 实例状态。取值范围：
     /// PENDING：表示创建中
@@ -580,7 +892,7 @@ pub struct Instance {
     /// REBOOTING：表示重启中
     /// SHUTDOWN：表示停止待销毁
     /// TERMINATING：表示销毁中
-    pub InstanceState: String,
+    pub InstanceState: InstanceState,
     
     /// 实例的CPU核数，单位：核
     pub CPU: i32,
@@ -632,4 +944,47 @@ pub struct Instance {
     /// 实例的网络接口
     #[serde(skip_serializing_if = "Option::is_none")]
     pub InternetAccessible: Option<InternetAccessible>,
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_instances_builder() {
+        let request = RunInstancesRequest::builder()
+            .zone("ap-guangzhou-6")
+            .image_id("img-6n21msk1")
+            .instance_type("S5.MEDIUM2")
+            .tag("env", "test")
+            .tag("team", "infra")
+            .build()
+            .unwrap();
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["Placement"]["Zone"], "ap-guangzhou-6");
+        assert_eq!(json["TagSpecification"][0]["Tags"][1]["Key"], "team");
+        assert!(json.get("DataDisks").is_none());
+    }
+
+    #[test]
+    fn test_run_instances_builder_validation() {
+        let missing_image = RunInstancesRequest::builder().zone("ap-guangzhou-6").build();
+        assert!(matches!(missing_image, Err(Error::ParameterError(_))));
+
+        let conflicting_login = RunInstancesRequest::builder()
+            .zone("ap-guangzhou-6")
+            .image_id("img-6n21msk1")
+            .password("Password123")
+            .key_ids(["skey-xxxxxxxx"])
+            .build();
+        assert!(matches!(conflicting_login, Err(Error::ParameterError(_))));
+
+        let from_template = RunInstancesRequest::builder()
+            .launch_template(LaunchTemplate {
+                LaunchTemplateId: Some("lt-xxxxxxxx".to_string()),
+                LaunchTemplateVersion: None,
+            })
+            .build();
+        assert!(from_template.is_ok());
+    }
+}
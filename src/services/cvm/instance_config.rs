@@ -4,14 +4,33 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::action::impl_action;
 use crate::client::TencentCloudClient;
 use crate::error::Result;
-use crate::services::cvm::instance::{ApiResponse, InstanceChargePrepaid};
+use crate::services::common::ApiResponse;
+pub use crate::services::common::Filter;
+use crate::services::cvm::instance::{InstanceChargePrepaid, RenewFlag};
+use crate::utils::request_builder::request_builder;
+use crate::utils::string_enum::string_enum;
 
 /// 查询用户配额详情请求参数
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct DescribeAccountQuotaRequest {}
 
+string_enum! {
+    /// 配额名称
+    pub enum QuotaId {
+        /// 竞价实例配额
+        TotalSpotInstanceQuotas = "TOTAL_SPOT_INSTANCE_QUOTAS",
+        /// CVM配额
+        TotalCvmQuotas = "TOTAL_CVM_QUOTAS",
+        /// 预付费CVM配额
+        TotalPrepaidCvmQuotas = "TOTAL_PREPAID_CVM_QUOTAS",
+        /// 后付费CVM配额
+        TotalPostpaidCvmQuotas = "TOTAL_POSTPAID_CVM_QUOTAS",
+    }
+}
+
 /// 用户配额详情
 #[derive(Debug, Clone, Deserialize)]
 pub struct AccountQuota {
@@ -20,7 +39,7 @@ pub struct AccountQuota {
     /// TOTAL_CVM_QUOTAS：CVM配额
     /// TOTAL_PREPAID_CVM_QUOTAS：预付费CVM配额
     /// TOTAL_POSTPAID_CVM_QUOTAS：后付费CVM配额
-    pub QuotaId: String,
+    pub QuotaId: QuotaId,
     
     /// 当前值
     pub QuotaCurrent: i32,
@@ -42,7 +61,7 @@ pub struct DescribeAccountQuotaResponse {
 /// 查询用户配额详情响应类型
 pub type DescribeAccountQuotaResponseType = ApiResponse<DescribeAccountQuotaResponse>;
 
-/// 创建定时任务请求参数
+/// 创建分散置放群组请求参数
 #[derive(Debug, Clone, Serialize)]
 pub struct CreateDisasterRecoverGroupRequest {
     /// 分散置放群组名称，长度1-60个字符，支持中、英文。
@@ -59,7 +78,23 @@ pub struct CreateDisasterRecoverGroupRequest {
     pub ClientToken: Option<String>,
 }
 
-/// 创建定时任务响应
+request_builder! {
+    /// 创建分散置放群组请求参数构造器
+    CreateDisasterRecoverGroupRequest => CreateDisasterRecoverGroupRequestBuilder {
+        required {
+            /// 设置分散置放群组名称
+            name => Name: String,
+            /// 设置分散置放群组类型
+            group_type => Type: String,
+        }
+        optional {
+            /// 设置用于保证请求幂等性的字符串
+            client_token => ClientToken: String,
+        }
+    }
+}
+
+/// 创建分散置放群组响应
 #[derive(Debug, Deserialize)]
 pub struct CreateDisasterRecoverGroupResponse {
     /// 分散置放群组ID列表。
@@ -87,7 +122,7 @@ pub struct CreateDisasterRecoverGroupResponse {
     pub RequestId: String,
 }
 
-/// 创建定时任务响应类型
+/// 创建分散置放群组响应类型
 pub type CreateDisasterRecoverGroupResponseType = ApiResponse<CreateDisasterRecoverGroupResponse>;
 
 /// 调整实例配置请求参数
@@ -117,6 +152,24 @@ pub struct ResizeInstancesRequest {
     pub ReserveHostName: Option<bool>,
 }
 
+request_builder! {
+    /// 调整实例配置请求参数构造器
+    ResizeInstancesRequest => ResizeInstancesRequestBuilder {
+        required {
+            /// 设置待操作的实例ID
+            instance_ids => InstanceIds: Vec<String>,
+            /// 设置实例机型
+            instance_type => InstanceType: String,
+        }
+        optional {
+            /// 设置是否对运行中的实例选择强制关机
+            force_stop => ForceStop: bool,
+            /// 设置是否同时切换私有网络
+            reserve_host_name => ReserveHostName: bool,
+        }
+    }
+}
+
 /// 调整实例配置响应
 #[derive(Debug, Deserialize)]
 pub struct ResizeInstancesResponse {
@@ -137,6 +190,19 @@ pub struct ModifyInstancesProjectRequest {
     pub ProjectId: i32,
 }
 
+request_builder! {
+    /// 修改实例所属项目请求参数构造器
+    ModifyInstancesProjectRequest => ModifyInstancesProjectRequestBuilder {
+        required {
+            /// 设置待操作的实例ID
+            instance_ids => InstanceIds: Vec<String>,
+            /// 设置项目ID
+            project_id => ProjectId: i32,
+        }
+        optional {}
+    }
+}
+
 /// 修改实例所属项目响应
 #[derive(Debug, Deserialize)]
 pub struct ModifyInstancesProjectResponse {
@@ -157,6 +223,19 @@ pub struct ModifyDisasterRecoverGroupAttributeRequest {
     pub Name: String,
 }
 
+request_builder! {
+    /// 调整实例分散置放群组请求参数构造器
+    ModifyDisasterRecoverGroupAttributeRequest => ModifyDisasterRecoverGroupAttributeRequestBuilder {
+        required {
+            /// 设置分散置放群组ID
+            disaster_recover_group_id => DisasterRecoverGroupId: String,
+            /// 设置分散置放群组名称
+            name => Name: String,
+        }
+        optional {}
+    }
+}
+
 /// 调整实例分散置放群组响应
 #[derive(Debug, Deserialize)]
 pub struct ModifyDisasterRecoverGroupAttributeResponse {
@@ -167,21 +246,32 @@ pub struct ModifyDisasterRecoverGroupAttributeResponse {
 /// 调整实例分散置放群组响应类型
 pub type ModifyDisasterRecoverGroupAttributeResponseType = ApiResponse<ModifyDisasterRecoverGroupAttributeResponse>;
 
-/// 删除定时任务请求参数
+/// 删除分散置放群组请求参数
 #[derive(Debug, Clone, Serialize)]
 pub struct DeleteDisasterRecoverGroupsRequest {
     /// 分散置放群组ID列表，可通过DescribeDisasterRecoverGroups接口获取。
     pub DisasterRecoverGroupIds: Vec<String>,
 }
 
-/// 删除定时任务响应
+request_builder! {
+    /// 删除分散置放群组请求参数构造器
+    DeleteDisasterRecoverGroupsRequest => DeleteDisasterRecoverGroupsRequestBuilder {
+        required {
+            /// 设置分散置放群组ID列表
+            disaster_recover_group_ids => DisasterRecoverGroupIds: Vec<String>,
+        }
+        optional {}
+    }
+}
+
+/// 删除分散置放群组响应
 #[derive(Debug, Deserialize)]
 pub struct DeleteDisasterRecoverGroupsResponse {
     /// 唯一请求 ID
     pub RequestId: String,
 }
 
-/// 删除定时任务响应类型
+/// 删除分散置放群组响应类型
 pub type DeleteDisasterRecoverGroupsResponseType = ApiResponse<DeleteDisasterRecoverGroupsResponse>;
 
 /// 修改实例计费模式请求参数
@@ -211,6 +301,24 @@ pub struct ModifyInstancesChargeTypeRequest {
     pub ModifyPortableDataDisk: Option<bool>,
 }
 
+request_builder! {
+    /// 修改实例计费模式请求参数构造器
+    ModifyInstancesChargeTypeRequest => ModifyInstancesChargeTypeRequestBuilder {
+        required {
+            /// 设置待操作的实例ID
+            instance_ids => InstanceIds: Vec<String>,
+            /// 设置实例计费模式
+            instance_charge_type => InstanceChargeType: String,
+        }
+        optional {
+            /// 设置预付费模式
+            instance_charge_prepaid => InstanceChargePrepaid: InstanceChargePrepaid,
+            /// 设置是否同时切换弹性数据云盘计费模式
+            modify_portable_data_disk => ModifyPortableDataDisk: bool,
+        }
+    }
+}
+
 /// 修改实例计费模式响应
 #[derive(Debug, Deserialize)]
 pub struct ModifyInstancesChargeTypeResponse {
@@ -231,7 +339,20 @@ pub struct ModifyInstancesRenewFlagRequest {
     /// NOTIFY_AND_AUTO_RENEW：通知过期且自动续费
     /// NOTIFY_AND_MANUAL_RENEW：通知过期不自动续费
     /// DISABLE_NOTIFY_AND_MANUAL_RENEW：不通知过期不自动续费
-    pub RenewFlag: String,
+    pub RenewFlag: RenewFlag,
+}
+
+request_builder! {
+    /// 修改实例续费标识请求参数构造器
+    ModifyInstancesRenewFlagRequest => ModifyInstancesRenewFlagRequestBuilder {
+        required {
+            /// 设置待操作的实例ID
+            instance_ids => InstanceIds: Vec<String>,
+            /// 设置自动续费标识
+            renew_flag => RenewFlag: RenewFlag,
+        }
+        optional {}
+    }
 }
 
 /// 修改实例续费标识响应
@@ -251,10 +372,28 @@ pub struct ModifyInstanceDiskTypeRequest {
     pub InstanceId: String,
     
     /// 实例数据盘配置信息，只需要指定要转换的目标类型，标识号不允许修改。
-    pub DataDisks: Vec<InstanceDiskType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub DataDisks: Option<Vec<InstanceDiskType>>,
     
     /// 实例系统盘配置信息，只需要指定要转换的目标类型，标识号不允许修改。
-    pub SystemDisk: InstanceDiskType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub SystemDisk: Option<InstanceDiskType>,
+}
+
+request_builder! {
+    /// 修改实例的硬盘介质请求参数构造器
+    ModifyInstanceDiskTypeRequest => ModifyInstanceDiskTypeRequestBuilder {
+        required {
+            /// 设置待操作的实例ID
+            instance_id => InstanceId: String,
+        }
+        optional {
+            /// 设置实例数据盘配置信息
+            data_disks => DataDisks: Vec<InstanceDiskType>,
+            /// 设置实例系统盘配置信息
+            system_disk => SystemDisk: InstanceDiskType,
+        }
+    }
 }
 
 /// 实例磁盘类型
@@ -288,6 +427,17 @@ pub struct EnterRescueModeRequest {
     pub InstanceId: String,
 }
 
+request_builder! {
+    /// 进入救援模式请求参数构造器
+    EnterRescueModeRequest => EnterRescueModeRequestBuilder {
+        required {
+            /// 设置待进入救援模式的实例ID
+            instance_id => InstanceId: String,
+        }
+        optional {}
+    }
+}
+
 /// 进入救援模式响应
 #[derive(Debug, Deserialize)]
 pub struct EnterRescueModeResponse {
@@ -305,6 +455,17 @@ pub struct ExitRescueModeRequest {
     pub InstanceId: String,
 }
 
+request_builder! {
+    /// 退出救援模式请求参数构造器
+    ExitRescueModeRequest => ExitRescueModeRequestBuilder {
+        required {
+            /// 设置待退出救援模式的实例ID
+            instance_id => InstanceId: String,
+        }
+        optional {}
+    }
+}
+
 /// 退出救援模式响应
 #[derive(Debug, Deserialize)]
 pub struct ExitRescueModeResponse {
@@ -315,18 +476,8 @@ pub struct ExitRescueModeResponse {
 /// 退出救援模式响应类型
 pub type ExitRescueModeResponseType = ApiResponse<ExitRescueModeResponse>;
 
-/// 查询可用区的机型配置信息的过滤条件
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Filter {
-    /// 过滤条件的名称
-    pub Name: String,
-    
-    /// 过滤条件的值
-    pub Values: Vec<String>,
-}
-
 /// 获取可用区机型配置信息请求
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct DescribeZoneInstanceConfigInfosRequest {
     /// 过滤条件，可支持的过滤条件如下：
     /// - zone: 按照可用区过滤，如 ap-guangzhou-1
@@ -583,6 +734,17 @@ pub struct DescribeInstancesAttributeRequest {
     pub InstanceIds: Vec<String>,
 }
 
+request_builder! {
+    /// 批量获取指定实例属性请求参数构造器
+    DescribeInstancesAttributeRequest => DescribeInstancesAttributeRequestBuilder {
+        required {
+            /// 设置待操作的实例ID
+            instance_ids => InstanceIds: Vec<String>,
+        }
+        optional {}
+    }
+}
+
 /// 批量获取指定实例属性响应
 #[derive(Debug, Deserialize)]
 pub struct DescribeInstancesAttributeResponse {
@@ -593,6 +755,23 @@ pub struct DescribeInstancesAttributeResponse {
 /// 批量获取指定实例属性响应类型
 pub type DescribeInstancesAttributeResponseType = ApiResponse<DescribeInstancesAttributeResponse>;
 
+impl_action! {
+    "cvm", "2017-03-12";
+    DescribeAccountQuotaRequest => DescribeAccountQuotaResponse = "DescribeAccountQuota";
+    CreateDisasterRecoverGroupRequest => CreateDisasterRecoverGroupResponse = "CreateDisasterRecoverGroup";
+    ResizeInstancesRequest => ResizeInstancesResponse = "ResizeInstances";
+    ModifyInstancesProjectRequest => ModifyInstancesProjectResponse = "ModifyInstancesProject";
+    ModifyDisasterRecoverGroupAttributeRequest => ModifyDisasterRecoverGroupAttributeResponse = "ModifyDisasterRecoverGroupAttribute";
+    DeleteDisasterRecoverGroupsRequest => DeleteDisasterRecoverGroupsResponse = "DeleteDisasterRecoverGroups";
+    ModifyInstancesChargeTypeRequest => ModifyInstancesChargeTypeResponse = "ModifyInstancesChargeType";
+    ModifyInstancesRenewFlagRequest => ModifyInstancesRenewFlagResponse = "ModifyInstancesRenewFlag";
+    ModifyInstanceDiskTypeRequest => ModifyInstanceDiskTypeResponse = "ModifyInstanceDiskType";
+    EnterRescueModeRequest => EnterRescueModeResponse = "EnterRescueMode";
+    ExitRescueModeRequest => ExitRescueModeResponse = "ExitRescueMode";
+    DescribeZoneInstanceConfigInfosRequest => DescribeZoneInstanceConfigInfosResponse = "DescribeZoneInstanceConfigInfos";
+    DescribeInstancesAttributeRequest => DescribeInstancesAttributeResponse = "DescribeInstancesAttribute";
+}
+
 /// 实例配置相关服务
 pub struct InstanceConfigService<'a> {
     client: &'a TencentCloudClient,
@@ -608,26 +787,14 @@ impl<'a> InstanceConfigService<'a> {
     /// 
     /// 本接口(DescribeAccountQuota)用于查询用户配额详情。
     pub async fn describe_account_quota(&self, request: &DescribeAccountQuotaRequest, region: &str) -> Result<DescribeAccountQuotaResponseType> {
-        self.client.request(
-            "DescribeAccountQuota", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
-    /// 创建定时任务
+    /// 创建分散置放群组
     /// 
     /// 本接口(CreateDisasterRecoverGroup)用于创建分散置放群组。
     pub async fn create_disaster_recover_group(&self, request: &CreateDisasterRecoverGroupRequest, region: &str) -> Result<CreateDisasterRecoverGroupResponseType> {
-        self.client.request(
-            "CreateDisasterRecoverGroup", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 调整实例配置
@@ -638,13 +805,7 @@ impl<'a> InstanceConfigService<'a> {
     /// - 允许升级实例的CPU、内存
     /// - 不同机型的增强型数据盘型号不同，所以总共有存储型、计算型等类型。调整配置的时候需要同类型对其调整。
     pub async fn resize_instances(&self, request: &ResizeInstancesRequest, region: &str) -> Result<ResizeInstancesResponseType> {
-        self.client.request(
-            "ResizeInstances", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 修改实例所属项目
@@ -655,41 +816,23 @@ impl<'a> InstanceConfigService<'a> {
     /// - 项目管理提供了资源管理与授权的功能，可以实现资源的集中管理与监控。
     /// - 该接口可以批量修改实例所属项目。
     pub async fn modify_instances_project(&self, request: &ModifyInstancesProjectRequest, region: &str) -> Result<ModifyInstancesProjectResponseType> {
-        self.client.request(
-            "ModifyInstancesProject", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 调整实例分散置放群组
     /// 
     /// 本接口(ModifyDisasterRecoverGroupAttribute)用于修改分散置放群组属性。
     pub async fn modify_disaster_recover_group_attribute(&self, request: &ModifyDisasterRecoverGroupAttributeRequest, region: &str) -> Result<ModifyDisasterRecoverGroupAttributeResponseType> {
-        self.client.request(
-            "ModifyDisasterRecoverGroupAttribute", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
-    /// 删除定时任务
+    /// 删除分散置放群组
     /// 
     /// 本接口(DeleteDisasterRecoverGroups)用于删除分散置放群组。
     /// 
     /// - 只有空的置放群组才能被删除，非空的群组需要先销毁组内所有云服务器，才能执行删除操作。
     pub async fn delete_disaster_recover_groups(&self, request: &DeleteDisasterRecoverGroupsRequest, region: &str) -> Result<DeleteDisasterRecoverGroupsResponseType> {
-        self.client.request(
-            "DeleteDisasterRecoverGroups", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 修改实例计费模式
@@ -700,39 +843,21 @@ impl<'a> InstanceConfigService<'a> {
     /// - 只支持将包年包月实例转换为按量计费实例
     /// - 不支持批量操作
     pub async fn modify_instances_charge_type(&self, request: &ModifyInstancesChargeTypeRequest, region: &str) -> Result<ModifyInstancesChargeTypeResponseType> {
-        self.client.request(
-            "ModifyInstancesChargeType", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 修改实例续费标识
     /// 
     /// 本接口(ModifyInstancesRenewFlag)用于修改包年包月实例续费标识。
     pub async fn modify_instances_renew_flag(&self, request: &ModifyInstancesRenewFlagRequest, region: &str) -> Result<ModifyInstancesRenewFlagResponseType> {
-        self.client.request(
-            "ModifyInstancesRenewFlag", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 修改实例的硬盘介质
     /// 
     /// 本接口(ModifyInstanceDiskType)用于修改实例的硬盘介质类型。
     pub async fn modify_instance_disk_type(&self, request: &ModifyInstanceDiskTypeRequest, region: &str) -> Result<ModifyInstanceDiskTypeResponseType> {
-        self.client.request(
-            "ModifyInstanceDiskType", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 进入救援模式
@@ -741,26 +866,14 @@ impl<'a> InstanceConfigService<'a> {
     /// 
     /// - 兼容了Linux和Windows操作系统
     pub async fn enter_rescue_mode(&self, request: &EnterRescueModeRequest, region: &str) -> Result<EnterRescueModeResponseType> {
-        self.client.request(
-            "EnterRescueMode", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 退出救援模式
     /// 
     /// 本接口(ExitRescueMode)用于退出救援模式。
     pub async fn exit_rescue_mode(&self, request: &ExitRescueModeRequest, region: &str) -> Result<ExitRescueModeResponseType> {
-        self.client.request(
-            "ExitRescueMode", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 获取可用区的机型配置信息
@@ -771,25 +884,13 @@ impl<'a> InstanceConfigService<'a> {
         request: &DescribeZoneInstanceConfigInfosRequest,
         region: &str,
     ) -> Result<DescribeZoneInstanceConfigInfosResponseType> {
-        self.client.request(
-            "DescribeZoneInstanceConfigInfos", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 批量获取指定实例属性
     /// 
     /// 本接口(DescribeInstancesAttribute)用于获取指定CVM实例的详细信息。
     pub async fn describe_instances_attribute(&self, request: &DescribeInstancesAttributeRequest, region: &str) -> Result<DescribeInstancesAttributeResponseType> {
-        self.client.request(
-            "DescribeInstancesAttribute", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
 } 
\ No newline at end of file
@@ -4,8 +4,34 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::action::impl_action;
 use crate::client::TencentCloudClient;
 use crate::error::Result;
+use crate::services::common::ApiResponse;
+use crate::utils::request_builder::request_builder;
+use crate::utils::string_enum::string_enum;
+
+string_enum! {
+    /// 关机类型
+    pub enum StopType {
+        /// 软关机
+        Soft = "SOFT",
+        /// 直接强制关机
+        Hard = "HARD",
+        /// 优先软关机，失败再执行强制关机
+        SoftFirst = "SOFT_FIRST",
+    }
+}
+
+string_enum! {
+    /// 按量计费实例关机收费模式
+    pub enum StoppedMode {
+        /// 关机继续收费
+        KeepCharging = "KEEP_CHARGING",
+        /// 关机停止收费
+        StopCharging = "STOP_CHARGING",
+    }
+}
 
 /// 启动实例的请求参数
 #[derive(Debug, Clone, Serialize)]
@@ -15,6 +41,17 @@ pub struct StartInstancesRequest {
     pub InstanceIds: Vec<String>,
 }
 
+request_builder! {
+    /// 启动实例请求参数构造器
+    StartInstancesRequest => StartInstancesRequestBuilder {
+        required {
+            /// 设置待操作的实例ID
+            instance_ids => InstanceIds: Vec<String>,
+        }
+        optional {}
+    }
+}
+
 /// 启动实例的响应
 #[derive(Debug, Deserialize)]
 pub struct StartInstancesResponse {
@@ -23,7 +60,7 @@ pub struct StartInstancesResponse {
 }
 
 /// 启动实例响应类型
-pub type StartInstancesResponseType = crate::services::cvm::instance::ApiResponse<StartInstancesResponse>;
+pub type StartInstancesResponseType = ApiResponse<StartInstancesResponse>;
 
 /// 重启实例的请求参数
 #[derive(Debug, Clone, Serialize)]
@@ -39,7 +76,7 @@ pub struct RebootInstancesRequest {
     /// 
     /// 默认取值：SOFT。
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub StopType: Option<String>,
+    pub StopType: Option<StopType>,
 
     /// 表示是否在正常重启失败后选择强制重启实例。
     /// true：表示在正常重启失败后进行强制重启
@@ -50,6 +87,22 @@ pub struct RebootInstancesRequest {
     pub ForceReboot: Option<bool>,
 }
 
+request_builder! {
+    /// 重启实例请求参数构造器
+    RebootInstancesRequest => RebootInstancesRequestBuilder {
+        required {
+            /// 设置待操作的实例ID
+            instance_ids => InstanceIds: Vec<String>,
+        }
+        optional {
+            /// 设置关机类型
+            stop_type => StopType: StopType,
+            /// 设置是否在正常重启失败后选择强制重启实例
+            force_reboot => ForceReboot: bool,
+        }
+    }
+}
+
 /// 重启实例的响应
 #[derive(Debug, Deserialize)]
 pub struct RebootInstancesResponse {
@@ -58,7 +111,7 @@ pub struct RebootInstancesResponse {
 }
 
 /// 重启实例响应类型
-pub type RebootInstancesResponseType = crate::services::cvm::instance::ApiResponse<RebootInstancesResponse>;
+pub type RebootInstancesResponseType = ApiResponse<RebootInstancesResponse>;
 
 /// 关闭实例的请求参数
 #[derive(Debug, Clone, Serialize)]
@@ -74,7 +127,7 @@ pub struct StopInstancesRequest {
     /// 
     /// 默认取值：SOFT。
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub StopType: Option<String>,
+    pub StopType: Option<StopType>,
 
     /// 表示是否在正常关闭失败后选择强制关闭实例。
     /// true：表示在正常关闭失败后进行强制关闭
@@ -92,7 +145,25 @@ pub struct StopInstancesRequest {
     /// 默认取值：KEEP_CHARGING。
     /// 该参数只针对部分按量计费云硬盘实例生效。
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub StoppedMode: Option<String>,
+    pub StoppedMode: Option<StoppedMode>,
+}
+
+request_builder! {
+    /// 关闭实例请求参数构造器
+    StopInstancesRequest => StopInstancesRequestBuilder {
+        required {
+            /// 设置待操作的实例ID
+            instance_ids => InstanceIds: Vec<String>,
+        }
+        optional {
+            /// 设置实例的关闭模式
+            stop_type => StopType: StopType,
+            /// 设置是否在正常关闭失败后选择强制关闭实例
+            force_stop => ForceStop: bool,
+            /// 设置按量计费实例关机收费模式
+            stopped_mode => StoppedMode: StoppedMode,
+        }
+    }
 }
 
 /// 关闭实例的响应
@@ -103,7 +174,7 @@ pub struct StopInstancesResponse {
 }
 
 /// 关闭实例响应类型
-pub type StopInstancesResponseType = crate::services::cvm::instance::ApiResponse<StopInstancesResponse>;
+pub type StopInstancesResponseType = ApiResponse<StopInstancesResponse>;
 
 /// 重置实例密码请求参数
 #[derive(Debug, Clone, Serialize)]
@@ -129,6 +200,24 @@ pub struct ResetInstancesPasswordRequest {
     pub UserName: Option<String>,
 }
 
+request_builder! {
+    /// 重置实例密码请求参数构造器
+    ResetInstancesPasswordRequest => ResetInstancesPasswordRequestBuilder {
+        required {
+            /// 设置待操作的实例ID
+            instance_ids => InstanceIds: Vec<String>,
+            /// 设置实例登录密码
+            password => Password: String,
+        }
+        optional {
+            /// 设置是否对运行中的实例选择强制关机
+            force_stop => ForceStop: bool,
+            /// 设置待重置密码的实例操作系统的用户名
+            user_name => UserName: String,
+        }
+    }
+}
+
 /// 重置实例密码响应
 #[derive(Debug, Deserialize)]
 pub struct ResetInstancesPasswordResponse {
@@ -137,7 +226,7 @@ pub struct ResetInstancesPasswordResponse {
 }
 
 /// 重置实例密码响应类型
-pub type ResetInstancesPasswordResponseType = crate::services::cvm::instance::ApiResponse<ResetInstancesPasswordResponse>;
+pub type ResetInstancesPasswordResponseType = ApiResponse<ResetInstancesPasswordResponse>;
 
 /// 修改实例属性请求参数
 #[derive(Debug, Clone, Serialize)]
@@ -175,6 +264,28 @@ pub struct ModifyInstancesAttributeRequest {
     pub InstanceChargeType: Option<String>,
 }
 
+request_builder! {
+    /// 修改实例属性请求参数构造器
+    ModifyInstancesAttributeRequest => ModifyInstancesAttributeRequestBuilder {
+        required {
+            /// 设置待操作的实例ID
+            instance_ids => InstanceIds: Vec<String>,
+        }
+        optional {
+            /// 设置实例名称
+            instance_name => InstanceName: String,
+            /// 设置实例所属安全组
+            security_groups => SecurityGroups: Vec<String>,
+            /// 设置是否开启实例销毁保护
+            disable_api_termination => DisableApiTermination: bool,
+            /// 设置实例关机模式
+            shutdown_behavior => ShutdownBehavior: String,
+            /// 设置实例计费模式
+            instance_charge_type => InstanceChargeType: String,
+        }
+    }
+}
+
 /// 修改实例属性响应
 #[derive(Debug, Deserialize)]
 pub struct ModifyInstancesAttributeResponse {
@@ -183,7 +294,7 @@ pub struct ModifyInstancesAttributeResponse {
 }
 
 /// 修改实例属性响应类型
-pub type ModifyInstancesAttributeResponseType = crate::services::cvm::instance::ApiResponse<ModifyInstancesAttributeResponse>;
+pub type ModifyInstancesAttributeResponseType = ApiResponse<ModifyInstancesAttributeResponse>;
 
 /// 续费实例请求参数
 #[derive(Debug, Clone, Serialize)]
@@ -203,6 +314,22 @@ pub struct RenewInstancesRequest {
     pub RenewPortableDataDisk: Option<bool>,
 }
 
+request_builder! {
+    /// 续费实例请求参数构造器
+    RenewInstancesRequest => RenewInstancesRequestBuilder {
+        required {
+            /// 设置待操作的实例ID
+            instance_ids => InstanceIds: Vec<String>,
+            /// 设置预付费模式
+            instance_charge_prepaid => InstanceChargePrepaid: crate::services::cvm::instance::InstanceChargePrepaid,
+        }
+        optional {
+            /// 设置是否续费弹性数据盘
+            renew_portable_data_disk => RenewPortableDataDisk: bool,
+        }
+    }
+}
+
 /// 续费实例响应
 #[derive(Debug, Deserialize)]
 pub struct RenewInstancesResponse {
@@ -211,7 +338,7 @@ pub struct RenewInstancesResponse {
 }
 
 /// 续费实例响应类型
-pub type RenewInstancesResponseType = crate::services::cvm::instance::ApiResponse<RenewInstancesResponse>;
+pub type RenewInstancesResponseType = ApiResponse<RenewInstancesResponse>;
 
 /// 重装实例请求参数
 #[derive(Debug, Clone, Serialize)]
@@ -240,6 +367,26 @@ pub struct ResetInstanceRequest {
     pub EnhancedService: Option<crate::services::cvm::instance::EnhancedService>,
 }
 
+request_builder! {
+    /// 重装实例请求参数构造器
+    ResetInstanceRequest => ResetInstanceRequestBuilder {
+        required {
+            /// 设置实例ID
+            instance_id => InstanceId: String,
+        }
+        optional {
+            /// 设置镜像ID
+            image_id => ImageId: String,
+            /// 设置实例系统盘配置信息
+            system_disk => SystemDisk: crate::services::cvm::instance::SystemDisk,
+            /// 设置实例登录设置
+            login_settings => LoginSettings: crate::services::cvm::instance::LoginSettings,
+            /// 设置增强服务
+            enhanced_service => EnhancedService: crate::services::cvm::instance::EnhancedService,
+        }
+    }
+}
+
 /// 重装实例响应
 #[derive(Debug, Deserialize)]
 pub struct ResetInstanceResponse {
@@ -248,7 +395,7 @@ pub struct ResetInstanceResponse {
 }
 
 /// 重装实例响应类型
-pub type ResetInstanceResponseType = crate::services::cvm::instance::ApiResponse<ResetInstanceResponse>;
+pub type ResetInstanceResponseType = ApiResponse<ResetInstanceResponse>;
 
 /// 退还实例请求参数
 #[derive(Debug, Clone, Serialize)]
@@ -264,6 +411,20 @@ pub struct TerminateInstancesRequest {
     pub ReleasePrepaidDataDisks: Option<bool>,
 }
 
+request_builder! {
+    /// 退还实例请求参数构造器
+    TerminateInstancesRequest => TerminateInstancesRequestBuilder {
+        required {
+            /// 设置待操作的实例ID
+            instance_ids => InstanceIds: Vec<String>,
+        }
+        optional {
+            /// 设置释放实例挂载的包年包月数据盘
+            release_prepaid_data_disks => ReleasePrepaidDataDisks: bool,
+        }
+    }
+}
+
 /// 退还实例响应
 #[derive(Debug, Deserialize)]
 pub struct TerminateInstancesResponse {
@@ -272,7 +433,19 @@ pub struct TerminateInstancesResponse {
 }
 
 /// 退还实例响应类型
-pub type TerminateInstancesResponseType = crate::services::cvm::instance::ApiResponse<TerminateInstancesResponse>;
+pub type TerminateInstancesResponseType = ApiResponse<TerminateInstancesResponse>;
+
+impl_action! {
+    "cvm", "2017-03-12";
+    StartInstancesRequest => StartInstancesResponse = "StartInstances";
+    RebootInstancesRequest => RebootInstancesResponse = "RebootInstances";
+    StopInstancesRequest => StopInstancesResponse = "StopInstances";
+    ResetInstancesPasswordRequest => ResetInstancesPasswordResponse = "ResetInstancesPassword";
+    ModifyInstancesAttributeRequest => ModifyInstancesAttributeResponse = "ModifyInstancesAttribute";
+    RenewInstancesRequest => RenewInstancesResponse = "RenewInstances";
+    ResetInstanceRequest => ResetInstanceResponse = "ResetInstance";
+    TerminateInstancesRequest => TerminateInstancesResponse = "TerminateInstances";
+}
 
 /// 实例操作相关服务
 pub struct InstanceOperationService<'a> {
@@ -293,13 +466,7 @@ impl<'a> InstanceOperationService<'a> {
     /// - 接口调用成功时，实例会进入STARTING状态；启动实例成功时，实例会进入RUNNING状态。
     /// - 本接口为异步接口，启动实例请求发送成功后会返回一个RequestId，此时操作并未立即完成。
     pub async fn start_instances(&self, request: &StartInstancesRequest, region: &str) -> Result<StartInstancesResponseType> {
-        self.client.request(
-            "StartInstances", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
 
     /// 重启实例
@@ -310,13 +477,7 @@ impl<'a> InstanceOperationService<'a> {
     /// - 接口调用成功时，实例会进入REBOOTING状态；重启实例成功时，实例会进入RUNNING状态。
     /// - 支持强制重启，强制重启可能会导致数据丢失或文件系统损坏，请仅在服务器不能正常重启时使用。
     pub async fn reboot_instances(&self, request: &RebootInstancesRequest, region: &str) -> Result<RebootInstancesResponseType> {
-        self.client.request(
-            "RebootInstances", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
 
     /// 关闭实例
@@ -328,13 +489,7 @@ impl<'a> InstanceOperationService<'a> {
     /// - 支持强制关闭，强制关闭可能会导致数据丢失或文件系统损坏，请仅在服务器不能正常关机时使用。
     /// - 本接口为异步接口，关闭实例请求发送成功后会返回一个RequestId，此时操作并未立即完成。
     pub async fn stop_instances(&self, request: &StopInstancesRequest, region: &str) -> Result<StopInstancesResponseType> {
-        self.client.request(
-            "StopInstances", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 重置实例密码
@@ -345,13 +500,7 @@ impl<'a> InstanceOperationService<'a> {
     /// - 批量操作的每个实例的重置密码结果可能不同，具体操作结果可以通过调用 DescribeInstances 接口查询。
     /// - 本接口为异步接口，重置密码请求发送成功后会返回一个RequestId，此时操作并未立即完成。
     pub async fn reset_instances_password(&self, request: &ResetInstancesPasswordRequest, region: &str) -> Result<ResetInstancesPasswordResponseType> {
-        self.client.request(
-            "ResetInstancesPassword", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 修改实例的属性
@@ -360,13 +509,7 @@ impl<'a> InstanceOperationService<'a> {
     /// 
     /// - 批量操作的每个实例的修改属性结果可能不同，具体操作结果可以通过调用 DescribeInstances 接口查询。
     pub async fn modify_instances_attribute(&self, request: &ModifyInstancesAttributeRequest, region: &str) -> Result<ModifyInstancesAttributeResponseType> {
-        self.client.request(
-            "ModifyInstancesAttribute", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 续费实例
@@ -376,13 +519,7 @@ impl<'a> InstanceOperationService<'a> {
     /// - 只支持操作包年包月实例。
     /// - 批量续费实例的续费时间将以所有实例中最短的剩余时间为准。例如，三个实例分别有1个月、2个月、3个月的剩余时间，续费2个月，则1个月的实例续费后剩余时间为3个月，2个月的实例续费后剩余时间为4个月，3个月的实例续费后剩余时间为5个月。
     pub async fn renew_instances(&self, request: &RenewInstancesRequest, region: &str) -> Result<RenewInstancesResponseType> {
-        self.client.request(
-            "RenewInstances", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 重装实例
@@ -393,13 +530,7 @@ impl<'a> InstanceOperationService<'a> {
     /// - 系统盘将会被格式化，并重置为指定的操作系统，其中数据盘的数据将保留不做处理。
     /// - 只有状态为RUNNING或者STOPPED的实例才可以进行此操作。
     pub async fn reset_instance(&self, request: &ResetInstanceRequest, region: &str) -> Result<ResetInstanceResponseType> {
-        self.client.request(
-            "ResetInstance", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 退还实例
@@ -410,12 +541,6 @@ impl<'a> InstanceOperationService<'a> {
     /// - 按量计费的实例通过本接口可直接退还；包年包月实例如符合退还规则，也可通过本接口主动退还。
     /// - 包年包月实例首次调用本接口，实例将被移至回收站，再次调用本接口，实例将被销毁，且不可恢复。按量计费实例调用本接口将被直接销毁。
     pub async fn terminate_instances(&self, request: &TerminateInstancesRequest, region: &str) -> Result<TerminateInstancesResponseType> {
-        self.client.request(
-            "TerminateInstances", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
 } 
\ No newline at end of file
@@ -4,9 +4,12 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::action::impl_action;
 use crate::client::TencentCloudClient;
-use crate::error::Result;
-use crate::services::cvm::instance::{ApiResponse, DataDisk, EnhancedService, InstanceChargePrepaid, InstanceChargeType, InternetAccessible, LoginSettings, Placement, SystemDisk, VirtualPrivateCloud};
+use crate::error::{Error, Result};
+use crate::services::common::{ApiResponse, Placement};
+use crate::services::cvm::instance::{DataDisk, EnhancedService, InstanceChargePrepaid, InstanceChargeType, InternetAccessible, LoginSettings, RunInstancesRequest, SystemDisk, VirtualPrivateCloud};
+use crate::utils::request_builder::request_builder;
 
 /// 创建实例询价请求参数
 #[derive(Debug, Clone, Serialize)]
@@ -63,6 +66,66 @@ pub struct InquiryPriceRunInstancesRequest {
     pub VirtualPrivateCloud: Option<VirtualPrivateCloud>,
 }
 
+request_builder! {
+    /// 创建实例询价请求参数构造器
+    InquiryPriceRunInstancesRequest => InquiryPriceRunInstancesRequestBuilder {
+        required {
+            /// 设置实例所在的位置
+            placement => Placement: Placement,
+            /// 设置镜像ID
+            image_id => ImageId: String,
+            /// 设置实例机型
+            instance_type => InstanceType: String,
+        }
+        optional {
+            /// 设置实例系统盘配置信息
+            system_disk => SystemDisk: SystemDisk,
+            /// 设置实例数据盘配置信息
+            data_disks => DataDisks: Vec<DataDisk>,
+            /// 设置公网带宽相关信息
+            internet_accessible => InternetAccessible: InternetAccessible,
+            /// 设置实例计费类型
+            instance_charge_type => InstanceChargeType: InstanceChargeType,
+            /// 设置预付费模式
+            instance_charge_prepaid => InstanceChargePrepaid: InstanceChargePrepaid,
+            /// 设置购买实例数量
+            instance_count => InstanceCount: i32,
+            /// 设置实例的登录设置
+            login_settings => LoginSettings: LoginSettings,
+            /// 设置增强服务
+            enhanced_service => EnhancedService: EnhancedService,
+            /// 设置私有网络相关信息
+            virtual_private_cloud => VirtualPrivateCloud: VirtualPrivateCloud,
+        }
+    }
+}
+
+/// 使用创建实例的请求参数询价，镜像ID和实例机型为必填参数
+impl TryFrom<RunInstancesRequest> for InquiryPriceRunInstancesRequest {
+    type Error = Error;
+
+    fn try_from(request: RunInstancesRequest) -> Result<Self> {
+        Ok(Self {
+            Placement: request.Placement.unwrap_or_default(),
+            ImageId: request
+                .ImageId
+                .ok_or_else(|| Error::ParameterError("缺少必填参数ImageId".to_string()))?,
+            InstanceType: request
+                .InstanceType
+                .ok_or_else(|| Error::ParameterError("缺少必填参数InstanceType".to_string()))?,
+            SystemDisk: request.SystemDisk,
+            DataDisks: request.DataDisks,
+            InternetAccessible: request.InternetAccessible,
+            InstanceChargeType: request.InstanceChargeType,
+            InstanceChargePrepaid: request.InstanceChargePrepaid,
+            InstanceCount: request.InstanceCount,
+            LoginSettings: request.LoginSettings,
+            EnhancedService: request.EnhancedService,
+            VirtualPrivateCloud: request.VirtualPrivateCloud,
+        })
+    }
+}
+
 /// 价格详情
 #[derive(Debug, Clone, Deserialize)]
 pub struct Price {
@@ -144,6 +207,22 @@ pub struct InquiryPriceRenewInstancesRequest {
     pub RenewPortableDataDisk: Option<bool>,
 }
 
+request_builder! {
+    /// 续费实例询价请求参数构造器
+    InquiryPriceRenewInstancesRequest => InquiryPriceRenewInstancesRequestBuilder {
+        required {
+            /// 设置待操作的实例ID
+            instance_ids => InstanceIds: Vec<String>,
+            /// 设置预付费模式
+            instance_charge_prepaid => InstanceChargePrepaid: InstanceChargePrepaid,
+        }
+        optional {
+            /// 设置是否续费弹性数据盘
+            renew_portable_data_disk => RenewPortableDataDisk: bool,
+        }
+    }
+}
+
 /// 续费实例询价响应
 #[derive(Debug, Deserialize)]
 pub struct InquiryPriceRenewInstancesResponse {
@@ -184,6 +263,26 @@ pub struct InquiryPriceResetInstanceRequest {
     pub EnhancedService: Option<EnhancedService>,
 }
 
+request_builder! {
+    /// 重装实例询价请求参数构造器
+    InquiryPriceResetInstanceRequest => InquiryPriceResetInstanceRequestBuilder {
+        required {
+            /// 设置实例ID
+            instance_id => InstanceId: String,
+        }
+        optional {
+            /// 设置镜像ID
+            image_id => ImageId: String,
+            /// 设置实例系统盘配置信息
+            system_disk => SystemDisk: SystemDisk,
+            /// 设置实例登录设置
+            login_settings => LoginSettings: LoginSettings,
+            /// 设置增强服务
+            enhanced_service => EnhancedService: EnhancedService,
+        }
+    }
+}
+
 /// 重装实例询价响应
 #[derive(Debug, Deserialize)]
 pub struct InquiryPriceResetInstanceResponse {
@@ -207,6 +306,19 @@ pub struct InquiryPriceResizeInstancesRequest {
     pub InstanceType: String,
 }
 
+request_builder! {
+    /// 调整实例配置询价请求参数构造器
+    InquiryPriceResizeInstancesRequest => InquiryPriceResizeInstancesRequestBuilder {
+        required {
+            /// 设置待操作的实例ID
+            instance_ids => InstanceIds: Vec<String>,
+            /// 设置实例机型
+            instance_type => InstanceType: String,
+        }
+        optional {}
+    }
+}
+
 /// 调整实例配置询价响应
 #[derive(Debug, Deserialize)]
 pub struct InquiryPriceResizeInstancesResponse {
@@ -237,6 +349,22 @@ pub struct InquiryPriceModifyInstancesChargeTypeRequest {
     pub InstanceChargePrepaid: Option<InstanceChargePrepaid>,
 }
 
+request_builder! {
+    /// 修改实例计费模式询价请求参数构造器
+    InquiryPriceModifyInstancesChargeTypeRequest => InquiryPriceModifyInstancesChargeTypeRequestBuilder {
+        required {
+            /// 设置待操作的实例ID
+            instance_ids => InstanceIds: Vec<String>,
+            /// 设置实例计费类型
+            instance_charge_type => InstanceChargeType: InstanceChargeType,
+        }
+        optional {
+            /// 设置预付费模式
+            instance_charge_prepaid => InstanceChargePrepaid: InstanceChargePrepaid,
+        }
+    }
+}
+
 /// 修改实例计费模式询价响应
 #[derive(Debug, Deserialize)]
 pub struct InquiryPriceModifyInstancesChargeTypeResponse {
@@ -269,6 +397,22 @@ pub struct InquiryPriceResizeInstanceDisksRequest {
     pub ForceStop: Option<bool>,
 }
 
+request_builder! {
+    /// 扩容实例磁盘询价请求参数构造器
+    InquiryPriceResizeInstanceDisksRequest => InquiryPriceResizeInstanceDisksRequestBuilder {
+        required {
+            /// 设置待操作的实例ID
+            instance_id => InstanceId: String,
+            /// 设置待扩容的数据盘配置信息
+            data_disks => DataDisks: Vec<DataDisk>,
+        }
+        optional {
+            /// 设置是否对运行中的实例选择强制关机
+            force_stop => ForceStop: bool,
+        }
+    }
+}
+
 /// 扩容实例磁盘询价响应
 #[derive(Debug, Deserialize)]
 pub struct InquiryPriceResizeInstanceDisksResponse {
@@ -289,6 +433,17 @@ pub struct InquiryPriceTerminateInstancesRequest {
     pub InstanceIds: Vec<String>,
 }
 
+request_builder! {
+    /// 退还实例询价请求参数构造器
+    InquiryPriceTerminateInstancesRequest => InquiryPriceTerminateInstancesRequestBuilder {
+        required {
+            /// 设置待操作的实例ID
+            instance_ids => InstanceIds: Vec<String>,
+        }
+        optional {}
+    }
+}
+
 /// 退还实例询价响应
 #[derive(Debug, Deserialize)]
 pub struct InquiryPriceTerminateInstancesResponse {
@@ -316,6 +471,17 @@ pub struct ResourcePrice {
 /// 退还实例询价响应类型
 pub type InquiryPriceTerminateInstancesResponseType = ApiResponse<InquiryPriceTerminateInstancesResponse>;
 
+impl_action! {
+    "cvm", "2017-03-12";
+    InquiryPriceRunInstancesRequest => InquiryPriceRunInstancesResponse = "InquiryPriceRunInstances";
+    InquiryPriceRenewInstancesRequest => InquiryPriceRenewInstancesResponse = "InquiryPriceRenewInstances";
+    InquiryPriceResetInstanceRequest => InquiryPriceResetInstanceResponse = "InquiryPriceResetInstance";
+    InquiryPriceResizeInstancesRequest => InquiryPriceResizeInstancesResponse = "InquiryPriceResizeInstances";
+    InquiryPriceModifyInstancesChargeTypeRequest => InquiryPriceModifyInstancesChargeTypeResponse = "InquiryPriceModifyInstancesChargeType";
+    InquiryPriceResizeInstanceDisksRequest => InquiryPriceResizeInstanceDisksResponse = "InquiryPriceResizeInstanceDisks";
+    InquiryPriceTerminateInstancesRequest => InquiryPriceTerminateInstancesResponse = "InquiryPriceTerminateInstances";
+}
+
 /// 实例价格相关服务
 pub struct InstancePriceService<'a> {
     client: &'a TencentCloudClient,
@@ -331,13 +497,7 @@ impl<'a> InstancePriceService<'a> {
     /// 
     /// 本接口(InquiryPriceRunInstances)用于创建实例询价。
     pub async fn inquiry_price_run_instances(&self, request: &InquiryPriceRunInstancesRequest, region: &str) -> Result<InquiryPriceRunInstancesResponseType> {
-        self.client.request(
-            "InquiryPriceRunInstances", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 续费实例询价
@@ -346,13 +506,7 @@ impl<'a> InstancePriceService<'a> {
     /// 
     /// - 只支持查询包年包月实例的续费价格。
     pub async fn inquiry_price_renew_instances(&self, request: &InquiryPriceRenewInstancesRequest, region: &str) -> Result<InquiryPriceRenewInstancesResponseType> {
-        self.client.request(
-            "InquiryPriceRenewInstances", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 重装实例询价
@@ -363,13 +517,7 @@ impl<'a> InstancePriceService<'a> {
     /// - 目前只支持系统盘类型是CLOUD_BASIC、CLOUD_PREMIUM、CLOUD_SSD类型的实例使用该接口实现重装询价操作。
     /// - 目前不支持境外地域的实例使用该接口实现重装询价操作。
     pub async fn inquiry_price_reset_instance(&self, request: &InquiryPriceResetInstanceRequest, region: &str) -> Result<InquiryPriceResetInstanceResponseType> {
-        self.client.request(
-            "InquiryPriceResetInstance", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 调整实例配置询价
@@ -379,13 +527,7 @@ impl<'a> InstancePriceService<'a> {
     /// - 目前只支持查询系统盘为云硬盘的实例配置变更的价格，且系统盘要挂载在实例上。
     /// - 目前不支持配置降级的价格查询。
     pub async fn inquiry_price_resize_instances(&self, request: &InquiryPriceResizeInstancesRequest, region: &str) -> Result<InquiryPriceResizeInstancesResponseType> {
-        self.client.request(
-            "InquiryPriceResizeInstances", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 修改实例计费模式询价
@@ -394,13 +536,7 @@ impl<'a> InstancePriceService<'a> {
     /// 
     /// - 目前只支持查询从包年包月转为按小时计费、以及从按小时计费转为包年包月的价格。
     pub async fn inquiry_price_modify_instances_charge_type(&self, request: &InquiryPriceModifyInstancesChargeTypeRequest, region: &str) -> Result<InquiryPriceModifyInstancesChargeTypeResponseType> {
-        self.client.request(
-            "InquiryPriceModifyInstancesChargeType", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 扩容实例磁盘询价
@@ -410,13 +546,7 @@ impl<'a> InstancePriceService<'a> {
     /// - 目前只支持扩容非弹性数据盘（挂载在实例上的数据盘）。
     /// - 目前不支持CDCPAID类型实例使用该接口扩容。
     pub async fn inquiry_price_resize_instance_disks(&self, request: &InquiryPriceResizeInstanceDisksRequest, region: &str) -> Result<InquiryPriceResizeInstanceDisksResponseType> {
-        self.client.request(
-            "InquiryPriceResizeInstanceDisks", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 退还实例询价
@@ -426,12 +556,6 @@ impl<'a> InstancePriceService<'a> {
     /// - 目前只支持查询包年包月实例的退还价格。
     /// - 只支持包年包月实例的退还询价。
     pub async fn inquiry_price_terminate_instances(&self, request: &InquiryPriceTerminateInstancesRequest, region: &str) -> Result<InquiryPriceTerminateInstancesResponseType> {
-        self.client.request(
-            "InquiryPriceTerminateInstances", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
 } 
\ No newline at end of file
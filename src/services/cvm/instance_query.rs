@@ -4,12 +4,17 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::action::impl_action;
 use crate::client::TencentCloudClient;
 use crate::error::Result;
-use crate::services::cvm::instance::{ApiResponse, Filter, Instance};
+use crate::paginator::{PagedRequest, PagedResponse, Paginator};
+use crate::services::common::{ApiResponse, Filter};
+use crate::services::cvm::instance::{Instance, InstanceChargeType, InstanceState};
+use crate::utils::request_builder::request_builder;
+use crate::utils::string_enum::string_enum;
 
 /// 查询实例列表的请求参数
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct DescribeInstancesRequest {
     /// 按照一个或者多个实例ID查询。实例ID例如：ins-xxxxxxxx。
     /// 每次请求的实例的上限为100。参数不支持同时指定InstanceIds和Filters。
@@ -29,6 +34,113 @@ pub struct DescribeInstancesRequest {
     pub Limit: Option<i32>,
 }
 
+impl DescribeInstancesRequest {
+    /// 创建按过滤条件查询的请求
+    pub fn with_filters(filters: InstanceFilters) -> Self {
+        Self {
+            Filters: Some(filters.build()),
+            ..Default::default()
+        }
+    }
+}
+
+/// 查询实例列表的过滤条件构造器
+///
+/// 同一过滤条件多次调用时取值之间为或的关系，不同过滤条件之间为与的关系。
+/// 每次请求最多10个过滤条件，每个过滤条件最多5个取值。
+#[derive(Debug, Clone, Default)]
+pub struct InstanceFilters {
+    filters: Vec<Filter>,
+}
+
+impl InstanceFilters {
+    /// 创建空的过滤条件
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加过滤条件的取值，已存在同名过滤条件时追加取值
+    fn push(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        let name = name.into();
+        match self.filters.iter_mut().find(|filter| filter.Name == name) {
+            Some(filter) => filter.Values.push(value.into()),
+            None => self.filters.push(Filter::new(name, [value])),
+        }
+        self
+    }
+
+    /// 按可用区过滤，如`ap-guangzhou-1`
+    pub fn zone(self, zone: impl Into<String>) -> Self {
+        self.push("zone", zone)
+    }
+
+    /// 按项目ID过滤
+    pub fn project_id(self, project_id: i64) -> Self {
+        self.push("project-id", project_id.to_string())
+    }
+
+    /// 按实例计费模式过滤
+    pub fn instance_charge_type(self, instance_charge_type: InstanceChargeType) -> Self {
+        self.push("instance-charge-type", instance_charge_type.as_str())
+    }
+
+    /// 按实例状态过滤
+    pub fn instance_state(self, instance_state: InstanceState) -> Self {
+        self.push("instance-state", instance_state.as_str())
+    }
+
+    /// 按实例主网卡的内网IP过滤
+    pub fn private_ip_address(self, private_ip_address: impl Into<String>) -> Self {
+        self.push("private-ip-address", private_ip_address)
+    }
+
+    /// 按实例主网卡的公网IP过滤，包含实例创建时自动分配的IP和实例创建后手动绑定的弹性IP
+    pub fn public_ip_address(self, public_ip_address: impl Into<String>) -> Self {
+        self.push("public-ip-address", public_ip_address)
+    }
+
+    /// 按私有网络ID过滤，如`vpc-xxxxxxxx`
+    pub fn vpc_id(self, vpc_id: impl Into<String>) -> Self {
+        self.push("vpc-id", vpc_id)
+    }
+
+    /// 按子网ID过滤，如`subnet-xxxxxxxx`
+    pub fn subnet_id(self, subnet_id: impl Into<String>) -> Self {
+        self.push("subnet-id", subnet_id)
+    }
+
+    /// 按实例名称过滤
+    pub fn instance_name(self, instance_name: impl Into<String>) -> Self {
+        self.push("instance-name", instance_name)
+    }
+
+    /// 按标签键过滤
+    pub fn tag_key(self, tag_key: impl Into<String>) -> Self {
+        self.push("tag-key", tag_key)
+    }
+
+    /// 按标签键值对过滤，即`tag:<标签键>`过滤条件
+    pub fn tag(self, key: impl AsRef<str>, value: impl Into<String>) -> Self {
+        self.push(format!("tag:{}", key.as_ref()), value)
+    }
+
+    /// 按安全组ID过滤，如`sg-xxxxxxxx`
+    pub fn security_group_id(self, security_group_id: impl Into<String>) -> Self {
+        self.push("security-group-id", security_group_id)
+    }
+
+    /// 生成过滤条件列表
+    pub fn build(self) -> Vec<Filter> {
+        self.filters
+    }
+}
+
+impl From<InstanceFilters> for Vec<Filter> {
+    fn from(filters: InstanceFilters) -> Self {
+        filters.build()
+    }
+}
+
 /// 查询实例列表的响应
 #[derive(Debug, Deserialize)]
 pub struct DescribeInstancesResponse {
@@ -45,8 +157,35 @@ pub struct DescribeInstancesResponse {
 /// 查询实例列表响应类型
 pub type DescribeInstancesResponseType = ApiResponse<DescribeInstancesResponse>;
 
+impl PagedRequest for DescribeInstancesRequest {
+    fn get_offset(&self) -> Option<i32> {
+        self.Offset
+    }
+
+    fn get_limit(&self) -> Option<i32> {
+        self.Limit
+    }
+
+    fn set_page(&mut self, offset: i32, limit: i32) {
+        self.Offset = Some(offset);
+        self.Limit = Some(limit);
+    }
+}
+
+impl PagedResponse for DescribeInstancesResponse {
+    type Item = Instance;
+
+    fn total_count(&self) -> i32 {
+        self.TotalCount
+    }
+
+    fn into_items(self) -> Vec<Instance> {
+        self.InstanceSet
+    }
+}
+
 /// 查询实例状态列表请求参数
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct DescribeInstancesStatusRequest {
     /// 按照一个或者多个实例ID查询。实例ID形如：ins-11112222。此参数的具体格式可参考API简介的ids.N一节。
     /// 每次请求的实例的上限为100。
@@ -78,7 +217,7 @@ pub struct InstanceStatus {
     /// REBOOTING：表示重启中
     /// SHUTDOWN：表示停止待销毁
     /// TERMINATING：表示销毁中
-    pub InstanceState: String,
+    pub InstanceState: InstanceState,
 }
 
 /// 查询实例状态列表响应
@@ -97,6 +236,33 @@ pub struct DescribeInstancesStatusResponse {
 /// 查询实例状态列表响应类型
 pub type DescribeInstancesStatusResponseType = ApiResponse<DescribeInstancesStatusResponse>;
 
+impl PagedRequest for DescribeInstancesStatusRequest {
+    fn get_offset(&self) -> Option<i32> {
+        self.Offset
+    }
+
+    fn get_limit(&self) -> Option<i32> {
+        self.Limit
+    }
+
+    fn set_page(&mut self, offset: i32, limit: i32) {
+        self.Offset = Some(offset);
+        self.Limit = Some(limit);
+    }
+}
+
+impl PagedResponse for DescribeInstancesStatusResponse {
+    type Item = InstanceStatus;
+
+    fn total_count(&self) -> i32 {
+        self.TotalCount
+    }
+
+    fn into_items(self) -> Vec<InstanceStatus> {
+        self.InstanceStatusSet
+    }
+}
+
 /// 查询实例可调整配置请求参数
 #[derive(Debug, Clone, Serialize)]
 pub struct DescribeInstancesModificationRequest {
@@ -112,6 +278,20 @@ pub struct DescribeInstancesModificationRequest {
     pub Status: Option<String>,
 }
 
+request_builder! {
+    /// 查询实例可调整配置请求参数构造器
+    DescribeInstancesModificationRequest => DescribeInstancesModificationRequestBuilder {
+        required {
+            /// 设置待操作的实例ID
+            instance_ids => InstanceIds: Vec<String>,
+        }
+        optional {
+            /// 设置当前实例状态
+            status => Status: String,
+        }
+    }
+}
+
 /// 实例可调整配置
 #[derive(Debug, Clone, Deserialize)]
 pub struct InstanceTypeConfig {
@@ -132,7 +312,7 @@ pub struct InstanceTypeConfig {
 
 /// 实例可调整配置详情
 #[derive(Debug, Clone, Deserialize)]
-pub struct InstanceTypeQuotaItem {
+pub struct InstanceModificationQuotaItem {
     /// 实例所在区域。
     pub Zone: String,
     
@@ -150,7 +330,7 @@ pub struct InstanceTypeQuotaItem {
 #[derive(Debug, Deserialize)]
 pub struct DescribeInstancesModificationResponse {
     /// 实例调整的配置详情
-    pub InstanceModificationQuotaSet: Vec<InstanceTypeQuotaItem>,
+    pub InstanceModificationQuotaSet: Vec<InstanceModificationQuotaItem>,
     
     /// 唯一请求 ID
     pub RequestId: String,
@@ -159,6 +339,16 @@ pub struct DescribeInstancesModificationResponse {
 /// 查询实例可调整配置响应类型
 pub type DescribeInstancesModificationResponseType = ApiResponse<DescribeInstancesModificationResponse>;
 
+string_enum! {
+    /// 实例操作
+    pub enum InstanceOperation {
+        /// 实例降配操作
+        InstanceDegrade = "INSTANCE_DEGRADE",
+        /// 修改网络带宽计费模式
+        InternetChargeTypeChange = "INTERNET_CHARGE_TYPE_CHANGE",
+    }
+}
+
 /// 查询实例操作限制请求参数
 #[derive(Debug, Clone, Serialize)]
 pub struct DescribeInstancesOperationLimitRequest {
@@ -171,27 +361,47 @@ pub struct DescribeInstancesOperationLimitRequest {
     /// 取值范围：
     /// INSTANCE_DEGRADE：实例降配操作
     /// INTERNET_CHARGE_TYPE_CHANGE：修改网络带宽计费模式
-    pub Operation: String,
+    pub Operation: InstanceOperation,
 }
 
-/// 实例操作限制明细
+request_builder! {
+    /// 查询实例操作限制请求参数构造器
+    DescribeInstancesOperationLimitRequest => DescribeInstancesOperationLimitRequestBuilder {
+        required {
+            /// 设置待查询的实例ID
+            instance_ids => InstanceIds: Vec<String>,
+            /// 设置实例操作
+            operation => Operation: InstanceOperation,
+        }
+        optional {}
+    }
+}
+
+/// 单台实例操作次数限制
 #[derive(Debug, Clone, Deserialize)]
-pub struct OperationDetail {
+pub struct OperationCountLimit {
+    /// 实例操作
+    pub Operation: InstanceOperation,
+    
     /// 实例ID
     pub InstanceId: String,
     
-    /// 实例操作限制详情
-    pub DisasterRecoverGroupIds: Vec<String>,
+    /// 当前已使用次数，返回-1表示该操作无次数限制
+    pub CurrentCount: i64,
     
-    /// 唯一请求 ID
-    pub RequestId: String,
+    /// 操作次数最高额度，返回-1表示该操作无次数限制，返回0表示不支持调整配置
+    pub LimitCount: i64,
 }
 
+/// 旧名称，字段与官方接口不一致，已改为`OperationCountLimit`
+#[deprecated(note = "请使用OperationCountLimit")]
+pub type OperationDetail = OperationCountLimit;
+
 /// 查询实例操作限制响应
 #[derive(Debug, Deserialize)]
 pub struct DescribeInstancesOperationLimitResponse {
     /// 该参数表示调整配置操作（降配）限制次数。
-    pub InstanceOperationLimitSet: Vec<OperationDetail>,
+    pub InstanceOperationLimitSet: Vec<OperationCountLimit>,
     
     /// 唯一请求 ID
     pub RequestId: String,
@@ -201,7 +411,7 @@ pub struct DescribeInstancesOperationLimitResponse {
 pub type DescribeInstancesOperationLimitResponseType = ApiResponse<DescribeInstancesOperationLimitResponse>;
 
 /// 查询所支持的实例机型族信息请求参数
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct DescribeInstanceFamilyConfigsRequest {}
 
 /// 机型族配置信息
@@ -228,7 +438,7 @@ pub struct DescribeInstanceFamilyConfigsResponse {
 pub type DescribeInstanceFamilyConfigsResponseType = ApiResponse<DescribeInstanceFamilyConfigsResponse>;
 
 /// 查询实例机型列表请求参数
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct DescribeInstanceTypeConfigsRequest {
     /// 过滤条件
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -253,10 +463,10 @@ pub struct InstanceTypeConfigItem {
     /// 内存大小，单位：GB
     pub Memory: i32,
     
-    /// 是否支持GPU
+    /// GPU核数，单位：核
     pub GPU: i32,
     
-    /// 本地存储块数量
+    /// FPGA核数，单位：核
     pub FPGA: i32,
 }
 
@@ -273,6 +483,16 @@ pub struct DescribeInstanceTypeConfigsResponse {
 /// 查询实例机型列表响应类型
 pub type DescribeInstanceTypeConfigsResponseType = ApiResponse<DescribeInstanceTypeConfigsResponse>;
 
+impl_action! {
+    "cvm", "2017-03-12";
+    DescribeInstancesRequest => DescribeInstancesResponse = "DescribeInstances";
+    DescribeInstancesStatusRequest => DescribeInstancesStatusResponse = "DescribeInstancesStatus";
+    DescribeInstancesModificationRequest => DescribeInstancesModificationResponse = "DescribeInstancesModification";
+    DescribeInstancesOperationLimitRequest => DescribeInstancesOperationLimitResponse = "DescribeInstancesOperationLimit";
+    DescribeInstanceFamilyConfigsRequest => DescribeInstanceFamilyConfigsResponse = "DescribeInstanceFamilyConfigs";
+    DescribeInstanceTypeConfigsRequest => DescribeInstanceTypeConfigsResponse = "DescribeInstanceTypeConfigs";
+}
+
 /// 实例查询服务
 pub struct InstanceQueryService<'a> {
     client: &'a TencentCloudClient,
@@ -292,13 +512,23 @@ impl<'a> InstanceQueryService<'a> {
     /// - 如果参数为空，返回当前用户一定数量（Limit所指定的数量，默认为20）的实例
     /// - 支持查询实例的最新操作（LatestOperation）以及最新操作状态(LatestOperationState)
     pub async fn describe_instances(&self, request: &DescribeInstancesRequest, region: &str) -> Result<DescribeInstancesResponseType> {
-        self.client.request(
-            "DescribeInstances", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
+    }
+    
+    /// 分页查询实例列表
+    /// 
+    /// 自动翻页直到取完所有符合条件的实例，通过`items()`逐个返回实例，
+    /// 可通过`concurrency()`设置并发预取的页数
+    pub fn describe_instances_paginator(&self, request: DescribeInstancesRequest, region: &str) -> Paginator<'a, DescribeInstancesRequest, DescribeInstancesResponse> {
+        let client = self.client;
+        let region = region.to_string();
+        Paginator::new(request, move |request| {
+            let region = region.clone();
+            async move {
+                let response = InstanceQueryService::new(client).describe_instances(&request, &region).await?;
+                Ok(response.Response)
+            }
+        })
     }
     
     /// 查看实例状态列表
@@ -308,52 +538,43 @@ impl<'a> InstanceQueryService<'a> {
     /// - 可以根据实例ID来查询实例的状态
     /// - 如果参数为空，返回当前用户一定数量（Limit所指定的数量，默认为20）的实例状态
     pub async fn describe_instances_status(&self, request: &DescribeInstancesStatusRequest, region: &str) -> Result<DescribeInstancesStatusResponseType> {
-        self.client.request(
-            "DescribeInstancesStatus", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
+    }
+    
+    /// 分页查看实例状态列表
+    /// 
+    /// 自动翻页直到取完所有实例的状态，通过`items()`逐个返回实例状态
+    pub fn describe_instances_status_paginator(&self, request: DescribeInstancesStatusRequest, region: &str) -> Paginator<'a, DescribeInstancesStatusRequest, DescribeInstancesStatusResponse> {
+        let client = self.client;
+        let region = region.to_string();
+        Paginator::new(request, move |request| {
+            let region = region.clone();
+            async move {
+                let response = InstanceQueryService::new(client).describe_instances_status(&request, &region).await?;
+                Ok(response.Response)
+            }
+        })
     }
     
     /// 查询实例可调整配置
     /// 
     /// 本接口 (DescribeInstancesModification) 用于查询实例可调整的机型配置。
     pub async fn describe_instances_modification(&self, request: &DescribeInstancesModificationRequest, region: &str) -> Result<DescribeInstancesModificationResponseType> {
-        self.client.request(
-            "DescribeInstancesModification", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 查询实例操作限制
     /// 
     /// 本接口用于查询实例操作限制信息。
     pub async fn describe_instances_operation_limit(&self, request: &DescribeInstancesOperationLimitRequest, region: &str) -> Result<DescribeInstancesOperationLimitResponseType> {
-        self.client.request(
-            "DescribeInstancesOperationLimit", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 查询所支持的实例机型族信息
     /// 
     /// 本接口 (DescribeInstanceFamilyConfigs) 查询当前用户和地域所支持的机型族信息。
     pub async fn describe_instance_family_configs(&self, request: &DescribeInstanceFamilyConfigsRequest, region: &str) -> Result<DescribeInstanceFamilyConfigsResponseType> {
-        self.client.request(
-            "DescribeInstanceFamilyConfigs", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        self.client.execute(request, Some(region)).await
     }
     
     /// 查询实例机型列表
@@ -363,12 +584,46 @@ impl<'a> InstanceQueryService<'a> {
     /// - 可以根据实例机型、实例族名称等信息来查询实例机型配置
     /// - 如果参数为空，返回当前用户地域所支持的所有机型配置
     pub async fn describe_instance_type_configs(&self, request: &DescribeInstanceTypeConfigsRequest, region: &str) -> Result<DescribeInstanceTypeConfigsResponseType> {
-        self.client.request(
-            "DescribeInstanceTypeConfigs", 
-            request, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
-    }
-} 
\ No newline at end of file
+        self.client.execute(request, Some(region)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instance_filters() {
+        let request = DescribeInstancesRequest::with_filters(
+            InstanceFilters::new()
+                .zone("ap-guangzhou-3")
+                .zone("ap-guangzhou-6")
+                .instance_state(InstanceState::Running)
+                .tag("env", "prod"),
+        );
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json["Filters"],
+            serde_json::json!([
+                {"Name": "zone", "Values": ["ap-guangzhou-3", "ap-guangzhou-6"]},
+                {"Name": "instance-state", "Values": ["RUNNING"]},
+                {"Name": "tag:env", "Values": ["prod"]},
+            ])
+        );
+        assert!(json.get("InstanceIds").is_none());
+    }
+
+    #[test]
+    fn test_operation_limit_response() {
+        let response: DescribeInstancesOperationLimitResponse = serde_json::from_value(serde_json::json!({
+            "InstanceOperationLimitSet": [
+                {"Operation": "INSTANCE_DEGRADE", "InstanceId": "ins-1", "CurrentCount": 1, "LimitCount": 3}
+            ],
+            "RequestId": "req-1"
+        }))
+        .unwrap();
+        let limit = &response.InstanceOperationLimitSet[0];
+        assert_eq!(limit.Operation, InstanceOperation::InstanceDegrade);
+        assert_eq!((limit.CurrentCount, limit.LimitCount), (1, 3));
+    }
+}
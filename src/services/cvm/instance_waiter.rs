@@ -0,0 +1,334 @@
+//! 实例状态等待
+//!
+//! 创建、启动、关闭或退还实例后，轮询DescribeInstancesStatus直到所有实例进入目标状态
+
+use std::collections::HashSet;
+use std::time::Duration;
+use tokio::time::Instant;
+
+use crate::client::TencentCloudClient;
+use crate::error::{Error, Result};
+use crate::services::cvm::instance::InstanceState;
+use crate::services::cvm::instance_query::{DescribeInstancesStatusRequest, InstanceQueryService, InstanceStatus};
+
+/// 默认的首次轮询间隔
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+/// 默认的最大轮询间隔
+pub const DEFAULT_MAX_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// 默认的等待超时时间
+pub const DEFAULT_WAIT_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// 默认的实例查询宽限时间，刚创建的实例可能短时间内查询不到
+pub const DEFAULT_MISSING_GRACE_PERIOD: Duration = Duration::from_secs(30);
+
+/// 单次查询的实例数量上限
+const MAX_INSTANCE_IDS: usize = 100;
+
+/// 无法再到达运行中或关机状态的实例状态
+const FAILED_STATES: &[InstanceState] = &[InstanceState::LaunchFailed, InstanceState::Shutdown, InstanceState::Terminating];
+
+/// 等待的目标
+#[derive(Debug, Clone)]
+enum Target {
+    /// 所有实例进入指定状态
+    State(InstanceState),
+    /// 所有实例已不存在
+    Absent,
+}
+
+/// 实例状态等待器
+///
+/// 轮询间隔从`poll_interval`开始，每次乘以`backoff`，最大不超过`max_poll_interval`。
+/// 超过`timeout`仍未到达目标状态时返回`Error::WaiterError`。
+/// 等待运行中或关机状态时，已查询到的实例消失，或超过`missing_grace_period`仍查询不到的实例，
+/// 会立即返回`Error::WaiterError`，避免实例ID错误时一直等到超时。
+pub struct InstanceWaiter<'a> {
+    client: &'a TencentCloudClient,
+    poll_interval: Duration,
+    max_poll_interval: Duration,
+    backoff: f64,
+    timeout: Duration,
+    missing_grace_period: Duration,
+}
+
+impl<'a> InstanceWaiter<'a> {
+    /// 创建新的实例状态等待器
+    pub fn new(client: &'a TencentCloudClient) -> Self {
+        Self {
+            client,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            max_poll_interval: DEFAULT_MAX_POLL_INTERVAL,
+            backoff: 1.5,
+            timeout: DEFAULT_WAIT_TIMEOUT,
+            missing_grace_period: DEFAULT_MISSING_GRACE_PERIOD,
+        }
+    }
+
+    /// 设置首次轮询间隔，默认为3秒
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// 设置最大轮询间隔，默认为15秒
+    pub fn max_poll_interval(mut self, max_poll_interval: Duration) -> Self {
+        self.max_poll_interval = max_poll_interval;
+        self
+    }
+
+    /// 设置轮询间隔的增长倍数，默认为1.5，设置为1.0时按固定间隔轮询
+    pub fn backoff(mut self, backoff: f64) -> Self {
+        self.backoff = backoff.max(1.0);
+        self
+    }
+
+    /// 设置等待超时时间，默认为10分钟
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// 设置查询不到实例时的宽限时间，默认为30秒
+    ///
+    /// 从未查询到的实例超过该时间后返回错误，不影响`wait_until_terminated`
+    pub fn missing_grace_period(mut self, missing_grace_period: Duration) -> Self {
+        self.missing_grace_period = missing_grace_period;
+        self
+    }
+
+    /// 等待所有实例进入运行中（RUNNING）状态
+    ///
+    /// 实例进入LAUNCH_FAILED、SHUTDOWN或TERMINATING状态或查询不到实例时返回错误
+    pub async fn wait_until_running(&self, instance_ids: &[String], region: &str) -> Result<Vec<InstanceStatus>> {
+        self.wait(instance_ids, region, Target::State(InstanceState::Running)).await
+    }
+
+    /// 等待所有实例进入关机（STOPPED）状态
+    ///
+    /// 实例进入LAUNCH_FAILED、SHUTDOWN或TERMINATING状态或查询不到实例时返回错误
+    pub async fn wait_until_stopped(&self, instance_ids: &[String], region: &str) -> Result<Vec<InstanceStatus>> {
+        self.wait(instance_ids, region, Target::State(InstanceState::Stopped)).await
+    }
+
+    /// 等待所有实例退还完成，即查询不到这些实例
+    ///
+    /// 包年包月实例退还后会进入回收站（SHUTDOWN状态），需要再次退还才会被销毁
+    pub async fn wait_until_terminated(&self, instance_ids: &[String], region: &str) -> Result<()> {
+        self.wait(instance_ids, region, Target::Absent).await.map(|_| ())
+    }
+
+    /// 轮询直到所有实例到达目标
+    async fn wait(&self, instance_ids: &[String], region: &str, target: Target) -> Result<Vec<InstanceStatus>> {
+        let start = Instant::now();
+        let deadline = start + self.timeout;
+        let mut interval = self.poll_interval;
+        let mut seen = HashSet::new();
+
+        loop {
+            let statuses = self.describe(instance_ids, region).await?;
+
+            let pending: Vec<String> = match &target {
+                Target::State(state) => {
+                    if let Some(failed) = statuses
+                        .iter()
+                        .find(|status| FAILED_STATES.contains(&status.InstanceState))
+                    {
+                        return Err(Error::WaiterError(format!(
+                            "实例{}进入{}状态，无法变为{}",
+                            failed.InstanceId, failed.InstanceState, state
+                        )));
+                    }
+                    seen.extend(statuses.iter().map(|status| status.InstanceId.clone()));
+                    let missing: Vec<&str> = instance_ids
+                        .iter()
+                        .filter(|id| !statuses.iter().any(|status| status.InstanceId == **id))
+                        .map(String::as_str)
+                        .collect();
+                    let grace_expired = start.elapsed() >= self.missing_grace_period;
+                    if !missing.is_empty() && (grace_expired || missing.iter().any(|id| seen.contains(*id))) {
+                        return Err(Error::WaiterError(format!(
+                            "查询不到以下实例，无法变为{}: {}",
+                            state,
+                            missing.join(", ")
+                        )));
+                    }
+                    instance_ids
+                        .iter()
+                        .filter_map(|id| match statuses.iter().find(|status| status.InstanceId == *id) {
+                            Some(status) if status.InstanceState == *state => None,
+                            Some(status) => Some(format!("{}({})", id, status.InstanceState)),
+                            None => Some(format!("{}(未找到)", id)),
+                        })
+                        .collect()
+                }
+                Target::Absent => statuses
+                    .iter()
+                    .map(|status| format!("{}({})", status.InstanceId, status.InstanceState))
+                    .collect(),
+            };
+
+            if pending.is_empty() {
+                return Ok(statuses);
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                let target = match &target {
+                    Target::State(state) => state.as_str(),
+                    Target::Absent => "已退还",
+                };
+                return Err(Error::WaiterError(format!(
+                    "等待{:?}后超时，以下实例未变为{}: {}",
+                    self.timeout,
+                    target,
+                    pending.join(", ")
+                )));
+            }
+
+            tokio::time::sleep(interval.min(deadline - now)).await;
+            interval = interval.mul_f64(self.backoff).min(self.max_poll_interval);
+        }
+    }
+
+    /// 查询实例状态，超过单次查询上限时分批查询
+    async fn describe(&self, instance_ids: &[String], region: &str) -> Result<Vec<InstanceStatus>> {
+        let service = InstanceQueryService::new(self.client);
+        let mut statuses = Vec::with_capacity(instance_ids.len());
+        for chunk in instance_ids.chunks(MAX_INSTANCE_IDS) {
+            let request = DescribeInstancesStatusRequest {
+                InstanceIds: Some(chunk.to_vec()),
+                Offset: None,
+                Limit: Some(MAX_INSTANCE_IDS as i32),
+            };
+            let response = service.describe_instances_status(&request, region).await?;
+            statuses.extend(response.Response.InstanceStatusSet);
+        }
+        Ok(statuses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ClientConfig;
+    use crate::credential::StaticCredentialProvider;
+    use crate::utils::test_server::TestServer;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// 启动按顺序返回实例状态的本地服务，最后一组状态会一直返回
+    async fn serve_states(states: Vec<Vec<(&'static str, &'static str)>>) -> TestServer {
+        let calls = AtomicUsize::new(0);
+        TestServer::start(move |_| {
+            let call = calls.fetch_add(1, Ordering::SeqCst).min(states.len() - 1);
+            let set: Vec<String> = states[call]
+                .iter()
+                .map(|(id, state)| format!(r#"{{"InstanceId":"{}","InstanceState":"{}"}}"#, id, state))
+                .collect();
+            let body = format!(
+                r#"{{"Response":{{"TotalCount":{},"InstanceStatusSet":[{}],"RequestId":"req-{}"}}}}"#,
+                set.len(),
+                set.join(","),
+                call
+            );
+            (200, body)
+        })
+        .await
+    }
+
+    fn client(server: &TestServer) -> TencentCloudClient {
+        let config = ClientConfig::new().endpoint("cvm", server.url.clone());
+        TencentCloudClient::with_config(StaticCredentialProvider::new("test_id", "test_key"), config).unwrap()
+    }
+
+    fn ids() -> Vec<String> {
+        vec!["ins-1".to_string(), "ins-2".to_string()]
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_running() {
+        let server = serve_states(vec![
+            vec![("ins-1", "PENDING")],
+            vec![("ins-1", "RUNNING"), ("ins-2", "PENDING")],
+            vec![("ins-1", "RUNNING"), ("ins-2", "RUNNING")],
+        ])
+        .await;
+        let client = client(&server);
+        let statuses = InstanceWaiter::new(&client)
+            .poll_interval(Duration::from_millis(1))
+            .wait_until_running(&ids(), "ap-guangzhou")
+            .await
+            .unwrap();
+        assert_eq!(statuses.len(), 2);
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_fails_early_on_launch_failed() {
+        let server = serve_states(vec![vec![("ins-1", "PENDING"), ("ins-2", "LAUNCH_FAILED")]]).await;
+        let client = client(&server);
+        let err = InstanceWaiter::new(&client)
+            .poll_interval(Duration::from_millis(1))
+            .wait_until_running(&ids(), "ap-guangzhou")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::WaiterError(ref message) if message.contains("ins-2")));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fails_on_missing_instance() {
+        // 从未查询到的实例超过宽限时间后返回错误，不等到超时
+        let server = serve_states(vec![vec![("ins-1", "RUNNING")]]).await;
+        let client = client(&server);
+        let err = InstanceWaiter::new(&client)
+            .poll_interval(Duration::from_millis(5))
+            .missing_grace_period(Duration::from_millis(20))
+            .wait_until_running(&ids(), "ap-guangzhou")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::WaiterError(ref message) if message.contains("ins-2") && !message.contains("超时")));
+    }
+
+    #[tokio::test]
+    async fn test_fails_when_instance_disappears() {
+        let server = serve_states(vec![
+            vec![("ins-1", "STOPPING"), ("ins-2", "STOPPING")],
+            vec![("ins-1", "STOPPED")],
+        ])
+        .await;
+        let client = client(&server);
+        let err = InstanceWaiter::new(&client)
+            .poll_interval(Duration::from_millis(1))
+            .wait_until_stopped(&ids(), "ap-guangzhou")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::WaiterError(ref message) if message.contains("ins-2")));
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_terminated() {
+        let server = serve_states(vec![vec![("ins-1", "TERMINATING")], vec![]]).await;
+        let client = client(&server);
+        InstanceWaiter::new(&client)
+            .poll_interval(Duration::from_millis(1))
+            .wait_until_terminated(&ids(), "ap-guangzhou")
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_timeout() {
+        let server = serve_states(vec![vec![("ins-1", "STOPPING"), ("ins-2", "STOPPED")]]).await;
+        let client = client(&server);
+        let err = InstanceWaiter::new(&client)
+            .poll_interval(Duration::from_millis(5))
+            .timeout(Duration::from_millis(30))
+            .wait_until_stopped(&ids(), "ap-guangzhou")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::WaiterError(ref message) if message.contains("ins-1(STOPPING)")));
+    }
+}
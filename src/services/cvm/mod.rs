@@ -6,4 +6,5 @@ pub mod instance;
 pub mod instance_operation;
 pub mod instance_query;
 pub mod instance_price;
-pub mod instance_config; 
\ No newline at end of file
+pub mod instance_config;
+pub mod instance_waiter; 
\ No newline at end of file
@@ -2,12 +2,21 @@
 //! 
 //! 本模块包含所有腾讯云API服务的实现
 
+// 公共数据结构
+pub mod common;
+
 // 地域相关接口
+#[cfg(feature = "region")]
 pub mod region;
 
 // CVM相关接口
+#[cfg(feature = "cvm")]
 pub mod cvm;
 
+// 安全凭证服务相关接口
+#[cfg(feature = "sts")]
+pub mod sts;
+
 // // 实例相关接口
 // pub mod instance;
 
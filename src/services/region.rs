@@ -4,8 +4,10 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::action::impl_action;
 use crate::client::TencentCloudClient;
 use crate::error::Result;
+pub use crate::services::common::{ApiError, ApiResponse, ErrorResponse, ErrorResponseType};
 
 /// 地域信息
 #[derive(Debug, Deserialize, Serialize)]
@@ -31,31 +33,6 @@ pub struct Zone {
     pub ZoneState: String,
 }
 
-/// API响应通用结构
-#[derive(Debug, Deserialize)]
-pub struct ApiResponse<T> {
-    /// 响应数据
-    pub Response: T,
-}
-
-/// API错误响应
-#[derive(Debug, Deserialize)]
-pub struct ErrorResponse {
-    /// 错误信息
-    pub Error: ApiError,
-    /// 请求ID
-    pub RequestId: String,
-}
-
-/// API错误
-#[derive(Debug, Deserialize)]
-pub struct ApiError {
-    /// 错误代码
-    pub Code: String,
-    /// 错误消息
-    pub Message: String,
-}
-
 /// 查询地域列表响应数据
 #[derive(Debug, Deserialize)]
 pub struct DescribeRegionsResponseData {
@@ -80,16 +57,24 @@ pub type DescribeRegionsResponse = ApiResponse<DescribeRegionsResponseData>;
 /// 完整的可用区响应类型
 pub type DescribeZonesResponse = ApiResponse<DescribeZonesResponseData>;
 
-/// 错误响应类型
-pub type ErrorResponseType = ApiResponse<ErrorResponse>;
-
 /// 查询可用区的请求参数
 #[derive(Debug, Serialize)]
 pub struct DescribeZonesRequest {
-    /// 地域ID
+    /// 地域ID，通过公共参数`X-TC-Region`传递，不包含在请求体中
+    #[serde(skip_serializing)]
     pub Region: String,
 }
 
+/// 查询地域列表请求参数
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DescribeRegionsRequest {}
+
+impl_action! {
+    "cvm", "2017-03-12";
+    DescribeRegionsRequest => DescribeRegionsResponseData = "DescribeRegions";
+    DescribeZonesRequest => DescribeZonesResponseData = "DescribeZones";
+}
+
 /// 地域服务
 pub struct RegionService<'a> {
     client: &'a TencentCloudClient,
@@ -105,17 +90,7 @@ impl<'a> RegionService<'a> {
     /// 
     /// 本接口(DescribeRegions)用于查询地域列表。
     pub async fn describe_regions(&self) -> Result<DescribeRegionsResponse> {
-        // 空参数请求
-        let params = ();
-        
-        // 使用client发送请求
-        self.client.request(
-            "DescribeRegions", 
-            &params, 
-            "cvm", 
-            "2017-03-12", 
-            None
-        ).await
+        self.client.execute(&DescribeRegionsRequest {}, None).await
     }
 
     /// 查询可用区列表
@@ -123,16 +98,7 @@ impl<'a> RegionService<'a> {
     /// 本接口(DescribeZones)用于查询可用区列表。
     /// 需要指定地域参数。
     pub async fn describe_zones(&self, region: &str) -> Result<DescribeZonesResponse> {
-        // 空参数请求 - Region参数通过最后一个参数传递
-        let params = ();
-        
-        // 使用client发送请求，将region作为第5个参数传递
-        self.client.request(
-            "DescribeZones", 
-            &params, 
-            "cvm", 
-            "2017-03-12", 
-            Some(region)
-        ).await
+        let request = DescribeZonesRequest { Region: region.to_string() };
+        self.client.execute(&request, Some(region)).await
     }
 } 
\ No newline at end of file
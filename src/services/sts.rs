@@ -0,0 +1,229 @@
+//! 安全凭证服务相关接口
+//!
+//! 包含申请扮演角色临时凭证和获取联合身份临时凭证的接口
+
+use serde::{Deserialize, Serialize};
+
+use crate::action::impl_action;
+use crate::client::TencentCloudClient;
+use crate::error::Result;
+pub use crate::services::common::{ApiResponse, Tag};
+
+/// 临时访问凭证
+#[derive(Debug, Clone, Deserialize)]
+pub struct Credentials {
+    /// token。token长度和绑定的策略有关，最长不超过4096字节。
+    pub Token: String,
+
+    /// 临时证书密钥ID。最长不超过1024字节。
+    pub TmpSecretId: String,
+
+    /// 临时证书密钥Key。最长不超过1024字节。
+    pub TmpSecretKey: String,
+}
+
+/// 申请扮演角色的请求参数
+#[derive(Debug, Clone, Serialize)]
+pub struct AssumeRoleRequest {
+    /// 角色的资源描述，可在访问管理，点击角色名获取。
+    /// 普通角色：qcs::cam::uin/12345678:role/4611686018427397919、qcs::cam::uin/12345678:roleName/testRoleName
+    /// 服务角色：qcs::cam::uin/12345678:role/tencentcloudServiceRole/4611686018427397920、qcs::cam::uin/12345678:role/tencentcloudServiceRoleName/testServiceRoleName
+    pub RoleArn: String,
+
+    /// 临时会话名称，由用户自定义名称。
+    /// 长度在2到128之间，可包含大小写字符，数字以及特殊字符：=,.@_-。 正则为：[\w+=,.@_-]*
+    pub RoleSessionName: String,
+
+    /// 指定临时访问凭证的有效期，单位：秒，默认 7200 秒，最长可设定有效期为 43200 秒
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub DurationSeconds: Option<u64>,
+
+    /// 策略描述，策略语法参照CAM策略语法。
+    /// 注意：策略需要经过URL编码；若不指定，临时凭证将拥有角色的全部权限。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub Policy: Option<String>,
+
+    /// 角色外部ID，可在访问管理，点击角色名获取。
+    /// 长度在2到128之间，可包含大小写字符，数字以及特殊字符：=,.@:/-。 正则为：[\w+=,.@:\/-]*
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ExternalId: Option<String>,
+
+    /// 会话标签列表。最多可以传递 50 个会话标签，不支持包含相同标签键。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub Tags: Option<Vec<Tag>>,
+
+    /// 调用者身份uin，如果不填，则默认为调用方的身份。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub SourceIdentity: Option<String>,
+}
+
+/// 申请扮演角色的响应
+#[derive(Debug, Deserialize)]
+pub struct AssumeRoleResponse {
+    /// 临时访问凭证
+    pub Credentials: Credentials,
+
+    /// 临时访问凭证的过期时间，返回Unix时间戳，精确到秒
+    pub ExpiredTime: u64,
+
+    /// 临时访问凭证的过期时间，以 iso8601 格式的 UTC 时间表示
+    pub Expiration: String,
+
+    /// 唯一请求 ID
+    pub RequestId: String,
+}
+
+/// 申请扮演角色响应类型
+pub type AssumeRoleResponseType = ApiResponse<AssumeRoleResponse>;
+
+/// 获取联合身份临时访问凭证的请求参数
+#[derive(Debug, Clone, Serialize)]
+pub struct GetFederationTokenRequest {
+    /// 您可以自定义调用方英文名称，由字母组成。
+    pub Name: String,
+
+    /// 授予该临时证书权限的CAM策略
+    /// 注意：
+    /// 1、策略语法参照CAM策略语法。
+    /// 2、策略中不能包含 principal 元素。
+    /// 3、该参数需要做urlencode。
+    pub Policy: String,
+
+    /// 指定临时证书的有效期，单位：秒，默认1800秒，主账号最长可设定有效期为7200秒，子账号最长可设定有效期为129600秒。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub DurationSeconds: Option<u64>,
+}
+
+/// 获取联合身份临时访问凭证的响应
+#[derive(Debug, Deserialize)]
+pub struct GetFederationTokenResponse {
+    /// 临时访问凭证
+    pub Credentials: Credentials,
+
+    /// 临时访问凭证有效的时间，返回 Unix 时间戳，精确到秒
+    pub ExpiredTime: u64,
+
+    /// 临时访问凭证有效的时间，以 iso8601 格式的 UTC 时间表示
+    pub Expiration: String,
+
+    /// 唯一请求 ID
+    pub RequestId: String,
+}
+
+/// 获取联合身份临时访问凭证响应类型
+pub type GetFederationTokenResponseType = ApiResponse<GetFederationTokenResponse>;
+
+impl_action! {
+    "sts", "2018-08-13";
+    AssumeRoleRequest => AssumeRoleResponse = "AssumeRole";
+    GetFederationTokenRequest => GetFederationTokenResponse = "GetFederationToken";
+}
+
+/// 安全凭证服务
+pub struct StsService<'a> {
+    client: &'a TencentCloudClient,
+}
+
+impl<'a> StsService<'a> {
+    /// 创建新的安全凭证服务
+    pub fn new(client: &'a TencentCloudClient) -> Self {
+        Self { client }
+    }
+
+    /// 申请扮演角色
+    ///
+    /// 本接口(AssumeRole)用于申请扮演角色的临时访问凭证。
+    pub async fn assume_role(&self, request: &AssumeRoleRequest, region: &str) -> Result<AssumeRoleResponseType> {
+        self.client.execute(request, Some(region)).await
+    }
+
+    /// 获取联合身份临时访问凭证
+    ///
+    /// 本接口(GetFederationToken)用于获取联合身份临时访问凭证。
+    pub async fn get_federation_token(&self, request: &GetFederationTokenRequest, region: &str) -> Result<GetFederationTokenResponseType> {
+        self.client.execute(request, Some(region)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ClientConfig;
+    use crate::credential::StaticCredentialProvider;
+    use crate::transport::MockTransport;
+    use serde_json::json;
+
+    fn credentials_response() -> serde_json::Value {
+        json!({
+            "Response": {
+                "Credentials": {"Token": "token", "TmpSecretId": "AKIDtmp", "TmpSecretKey": "tmpkey"},
+                "ExpiredTime": 1543914376,
+                "Expiration": "2018-12-04T09:06:16Z",
+                "RequestId": "req-1"
+            }
+        })
+    }
+
+    fn client(mock: &MockTransport) -> TencentCloudClient {
+        TencentCloudClient::with_transport(
+            StaticCredentialProvider::new("test_id", "test_key"),
+            ClientConfig::default(),
+            mock.clone(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_assume_role() {
+        let mock = MockTransport::new();
+        mock.when("AssumeRole").service("sts").respond_json(credentials_response());
+        let client = client(&mock);
+
+        let request = AssumeRoleRequest {
+            RoleArn: "qcs::cam::uin/100:roleName/test".to_string(),
+            RoleSessionName: "session".to_string(),
+            DurationSeconds: None,
+            Policy: None,
+            ExternalId: None,
+            Tags: Some(vec![Tag { Key: "tenant".to_string(), Value: "a".to_string() }]),
+            SourceIdentity: None,
+        };
+        let response = StsService::new(&client).assume_role(&request, "ap-guangzhou").await.unwrap().Response;
+        assert_eq!(response.Credentials.TmpSecretId, "AKIDtmp");
+        assert_eq!(response.ExpiredTime, 1543914376);
+
+        let sent = &mock.requests_for("AssumeRole")[0];
+        assert_eq!(sent.version, "2018-08-13");
+        assert!(sent.url.contains("sts.tencentcloudapi.com"));
+        let payload: serde_json::Value = serde_json::from_str(&sent.payload).unwrap();
+        assert_eq!(
+            payload,
+            json!({
+                "RoleArn": "qcs::cam::uin/100:roleName/test",
+                "RoleSessionName": "session",
+                "Tags": [{"Key": "tenant", "Value": "a"}]
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_federation_token() {
+        let mock = MockTransport::new();
+        mock.when("GetFederationToken").service("sts").respond_json(credentials_response());
+        let client = client(&mock);
+
+        let request = GetFederationTokenRequest {
+            Name: "deployer".to_string(),
+            Policy: "%7B%22version%22%3A%222.0%22%7D".to_string(),
+            DurationSeconds: Some(1800),
+        };
+        let response = StsService::new(&client).get_federation_token(&request, "ap-guangzhou").await.unwrap().Response;
+        assert_eq!(response.Credentials.Token, "token");
+
+        let sent = &mock.requests_for("GetFederationToken")[0];
+        let payload: serde_json::Value = serde_json::from_str(&sent.payload).unwrap();
+        assert_eq!(
+            payload,
+            json!({"Name": "deployer", "Policy": "%7B%22version%22%3A%222.0%22%7D", "DurationSeconds": 1800})
+        );
+    }
+}
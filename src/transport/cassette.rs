@@ -0,0 +1,319 @@
+//! 录制和回放传输层
+//!
+//! 录制模式下把真实请求和响应保存为JSON文件，回放模式下从文件读取响应，用于在没有网络的环境中测试
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use super::{Transport, TransportRequest, TransportResponse};
+use crate::error::{Error, Result};
+use crate::utils::http::redact_headers;
+use crate::utils::signature::sha256_hex;
+
+/// 录制时默认需要脱敏的JSON字段，包含密码和临时凭证
+const SCRUBBED_FIELDS: &[&str] = &["Password", "SecretId", "SecretKey", "TmpSecretId", "TmpSecretKey", "Token"];
+
+/// 脱敏后的取值
+const SCRUBBED: &str = "***";
+
+/// 录制文件的内容
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Cassette {
+    service: String,
+    action: String,
+    version: String,
+    region: Option<String>,
+    /// 脱敏后的请求头，敏感请求头与日志脱敏使用同一列表
+    headers: Vec<(String, String)>,
+    /// 脱敏后的请求体
+    payload: Value,
+    /// 按顺序录制的响应
+    responses: Vec<CassetteResponse>,
+}
+
+/// 录制的响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CassetteResponse {
+    status: u16,
+    /// 脱敏后的响应体，不是JSON时保存为字符串
+    body: Value,
+}
+
+impl CassetteResponse {
+    /// 还原为传输层响应
+    fn to_response(&self) -> TransportResponse {
+        let body = match &self.body {
+            Value::String(text) => text.clone(),
+            body => body.to_string(),
+        };
+        TransportResponse::new(self.status, body)
+    }
+}
+
+/// 工作模式
+enum Mode {
+    /// 转发到内部传输层并录制
+    Record(Arc<dyn Transport>),
+    /// 从录制文件回放
+    Replay,
+}
+
+#[derive(Default)]
+struct State {
+    /// 本次录制的内容，首次录制某个请求时覆盖已有文件
+    recorded: HashMap<String, Cassette>,
+    /// 回放时已读取的录制文件和下一个响应的位置
+    replayed: HashMap<String, (Cassette, usize)>,
+}
+
+/// 录制和回放传输层
+///
+/// 录制文件保存在指定目录下，文件名为`<接口名称>-<请求体哈希>.json`，哈希根据脱敏前的请求体（GET请求为查询字符串）计算，
+/// 因此回放时需要发送与录制时相同的请求体。同一请求多次录制的响应按顺序回放，用完后重复最后一个响应，
+/// 适合轮询实例状态等场景。
+///
+/// 录制时`Authorization`、`X-TC-Token`请求头以及请求体和响应体中的密码、密钥、令牌字段会被替换为`***`。
+/// 回放时配合`ClientConfig::signing_timestamp`固定签名时间戳，每次运行发出的请求完全一致。
+pub struct CassetteTransport {
+    dir: PathBuf,
+    mode: Mode,
+    scrubbed_fields: Vec<String>,
+    state: Mutex<State>,
+}
+
+impl CassetteTransport {
+    /// 创建录制模式的传输层，请求会转发到`inner`（通常为`HttpClient`）
+    pub fn record<T: Transport + 'static>(dir: impl Into<PathBuf>, inner: T) -> Self {
+        Self::with_mode(dir.into(), Mode::Record(Arc::new(inner)))
+    }
+
+    /// 创建回放模式的传输层，找不到录制文件时返回`Error::Other`
+    pub fn replay(dir: impl Into<PathBuf>) -> Self {
+        Self::with_mode(dir.into(), Mode::Replay)
+    }
+
+    fn with_mode(dir: PathBuf, mode: Mode) -> Self {
+        Self {
+            dir,
+            mode,
+            scrubbed_fields: SCRUBBED_FIELDS.iter().map(|field| field.to_string()).collect(),
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// 添加录制时需要脱敏的JSON字段名称
+    pub fn scrub_field(mut self, field: impl Into<String>) -> Self {
+        self.scrubbed_fields.push(field.into());
+        self
+    }
+
+    /// 获取录制文件的目录
+    pub fn get_dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// 计算请求对应的录制文件名，GET请求的哈希根据URL中的查询字符串计算
+    pub fn cassette_name(request: &TransportRequest) -> String {
+        let hash = match request.url.split_once('?') {
+            Some((_, query)) => sha256_hex(&format!("{}\n{}", query, request.payload)),
+            None => sha256_hex(&request.payload),
+        };
+        format!("{}-{}.json", request.action, &hash[..16])
+    }
+
+    /// 录制请求和响应，并写入文件
+    fn save(&self, name: &str, request: &TransportRequest, response: &TransportResponse) -> Result<()> {
+        let cassette = {
+            let mut state = self.state.lock().unwrap();
+            let cassette = state.recorded.entry(name.to_string()).or_insert_with(|| Cassette {
+                service: request.service.clone(),
+                action: request.action.clone(),
+                version: request.version.clone(),
+                region: request.region.clone(),
+                headers: redact_headers(&request.headers),
+                payload: self.scrub_text(&request.payload),
+                responses: Vec::new(),
+            });
+            cassette.responses.push(CassetteResponse {
+                status: response.status,
+                body: self.scrub_text(&response.body),
+            });
+            cassette.clone()
+        };
+
+        let content = serde_json::to_string_pretty(&cassette)?;
+        std::fs::create_dir_all(&self.dir)
+            .and_then(|_| std::fs::write(self.dir.join(name), content))
+            .map_err(|e| Error::Other(format!("写入录制文件{}失败: {}", name, e)))
+    }
+
+    /// 回放录制的响应
+    fn load(&self, name: &str) -> Result<TransportResponse> {
+        let mut state = self.state.lock().unwrap();
+        if !state.replayed.contains_key(name) {
+            let path = self.dir.join(name);
+            let content = std::fs::read_to_string(&path)
+                .map_err(|e| Error::Other(format!("读取录制文件{}失败: {}", path.display(), e)))?;
+            let cassette: Cassette = serde_json::from_str(&content)?;
+            if cassette.responses.is_empty() {
+                return Err(Error::Other(format!("录制文件{}中没有响应", path.display())));
+            }
+            state.replayed.insert(name.to_string(), (cassette, 0));
+        }
+
+        let (cassette, next) = state.replayed.get_mut(name).unwrap();
+        let response = cassette.responses[(*next).min(cassette.responses.len() - 1)].to_response();
+        *next += 1;
+        Ok(response)
+    }
+
+    /// 解析为JSON并脱敏，不是JSON时按表单参数脱敏后保存为字符串
+    fn scrub_text(&self, text: &str) -> Value {
+        match serde_json::from_str::<Value>(text) {
+            Ok(mut value) => {
+                scrub_value(&mut value, &self.scrubbed_fields);
+                value
+            }
+            Err(_) => Value::String(scrub_form(text, &self.scrubbed_fields)),
+        }
+    }
+}
+
+#[async_trait]
+impl Transport for CassetteTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+        let name = Self::cassette_name(&request);
+        match &self.mode {
+            Mode::Record(inner) => {
+                let response = inner.send(request.clone()).await?;
+                self.save(&name, &request, &response)?;
+                Ok(response)
+            }
+            Mode::Replay => self.load(&name),
+        }
+    }
+}
+
+/// 递归替换敏感字段的值
+fn scrub_value(value: &mut Value, fields: &[String]) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if fields.iter().any(|field| field == key) && !value.is_null() {
+                    *value = Value::String(SCRUBBED.to_string());
+                } else {
+                    scrub_value(value, fields);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(|item| scrub_value(item, fields)),
+        _ => {}
+    }
+}
+
+/// 替换表单参数中敏感字段的值，参数名按`.`分隔后的最后一段匹配，如`LoginSettings.Password`
+fn scrub_form(text: &str, fields: &[String]) -> String {
+    if !text.contains('=') {
+        return text.to_string();
+    }
+    text.split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((key, _)) if fields.iter().any(|field| key.rsplit('.').next() == Some(field.as_str())) => {
+                format!("{}={}", key, SCRUBBED)
+            }
+            _ => pair.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+    use crate::utils::signature::SignatureInfo;
+
+    fn request(action: &str, payload: &str) -> TransportRequest {
+        let signature_info = SignatureInfo {
+            method: "POST".to_string(),
+            url: "https://sts.tencentcloudapi.com".to_string(),
+            headers: vec![
+                ("Authorization".to_string(), "TC3-HMAC-SHA256 Credential=AKIDsecret/2024-01-01/sts/tc3_request".to_string()),
+                ("X-TC-Token".to_string(), "session-token".to_string()),
+                ("X-TC-Action".to_string(), action.to_string()),
+            ],
+            payload: payload.to_string(),
+        };
+        TransportRequest::new("sts", action, "2018-08-13", Some("ap-guangzhou"), signature_info)
+    }
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        let dir = std::env::temp_dir().join(format!("tencentcloud_cassettes_{}", std::process::id()));
+        let mock = MockTransport::new();
+        let calls = std::sync::atomic::AtomicUsize::new(0);
+        mock.when("AssumeRole").respond_with(move |_| {
+            let call = calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(TransportResponse::new(
+                200,
+                format!(
+                    r#"{{"Response":{{"Credentials":{{"Token":"t","TmpSecretId":"id","TmpSecretKey":"key"}},"ExpiredTime":{},"RequestId":"req-{}"}}}}"#,
+                    call, call
+                ),
+            ))
+        });
+
+        let payload = r#"{"RoleArn":"qcs::cam::uin/1:roleName/test","RoleSessionName":"test"}"#;
+        let recorder = CassetteTransport::record(&dir, mock);
+        recorder.send(request("AssumeRole", payload)).await.unwrap();
+        recorder.send(request("AssumeRole", payload)).await.unwrap();
+
+        let name = CassetteTransport::cassette_name(&request("AssumeRole", payload));
+        let content = std::fs::read_to_string(dir.join(&name)).unwrap();
+        assert!(!content.contains("AKIDsecret"));
+        assert!(!content.contains("session-token"));
+        assert!(!content.contains("TmpSecretKey\": \"key"));
+
+        let replayer = CassetteTransport::replay(&dir);
+        let mut bodies = Vec::new();
+        for _ in 0..3 {
+            let response = replayer.send(request("AssumeRole", payload)).await.unwrap();
+            bodies.push(serde_json::from_str::<Value>(&response.body).unwrap());
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(bodies[0]["Response"]["RequestId"], "req-0");
+        assert_eq!(bodies[1]["Response"]["RequestId"], "req-1");
+        assert_eq!(bodies[2]["Response"]["RequestId"], "req-1");
+        assert_eq!(bodies[0]["Response"]["Credentials"]["TmpSecretKey"], SCRUBBED);
+        assert!(replayer.send(request("AssumeRole", "{}")).await.is_err());
+    }
+
+    #[test]
+    fn test_scrub_value() {
+        let mut value = serde_json::json!({
+            "LoginSettings": {"Password": "secret", "KeyIds": null},
+            "Items": [{"Token": "t"}],
+            "InstanceName": "web",
+        });
+        scrub_value(&mut value, &["Password".to_string(), "Token".to_string(), "KeyIds".to_string()]);
+        assert_eq!(value["LoginSettings"]["Password"], SCRUBBED);
+        assert!(value["LoginSettings"]["KeyIds"].is_null());
+        assert_eq!(value["Items"][0]["Token"], SCRUBBED);
+        assert_eq!(value["InstanceName"], "web");
+    }
+
+    #[test]
+    fn test_scrub_form() {
+        let fields = vec!["Password".to_string()];
+        assert_eq!(
+            scrub_form("InstanceName=web&LoginSettings.Password=secret", &fields),
+            "InstanceName=web&LoginSettings.Password=***"
+        );
+        assert_eq!(scrub_form("not json", &fields), "not json");
+    }
+}
@@ -0,0 +1,236 @@
+//! 内存中的模拟传输层
+//!
+//! 按接口名称、服务和地域匹配请求并返回预设的响应，同时记录收到的请求，用于离线测试
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex};
+
+use super::{Transport, TransportRequest, TransportResponse};
+use crate::error::{Error, Result};
+
+/// 模拟错误响应使用的请求ID
+pub const MOCK_REQUEST_ID: &str = "mock-request-id";
+
+/// 生成响应的函数
+type Responder = Arc<dyn Fn(&TransportRequest) -> Result<TransportResponse> + Send + Sync>;
+
+/// 匹配规则
+struct Rule {
+    action: String,
+    service: Option<String>,
+    region: Option<String>,
+    responder: Responder,
+}
+
+impl Rule {
+    /// 判断请求是否匹配
+    fn matches(&self, request: &TransportRequest) -> bool {
+        self.action == request.action
+            && self.service.as_ref().is_none_or(|service| *service == request.service)
+            && self.region.as_ref().is_none_or(|region| Some(region) == request.region.as_ref())
+    }
+}
+
+#[derive(Default)]
+struct State {
+    rules: Vec<Rule>,
+    requests: Vec<TransportRequest>,
+}
+
+/// 模拟传输层
+///
+/// 按添加顺序匹配规则，使用第一条匹配的规则生成响应；没有匹配的规则时返回`Error::Other`。
+/// 克隆后共享规则和请求记录，可以把一份交给客户端，另一份用于检查收到的请求。
+///
+/// ```
+/// use serde_json::json;
+/// use tencent_cloud_sdk::transport::MockTransport;
+///
+/// let mock = MockTransport::new();
+/// mock.when("DescribeRegions")
+///     .service("cvm")
+///     .respond_json(json!({"Response": {"TotalCount": 0, "RegionSet": [], "RequestId": "req-1"}}));
+/// mock.when("RunInstances").respond_error("InvalidParameter", "参数错误");
+/// ```
+#[derive(Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<State>>,
+}
+
+impl MockTransport {
+    /// 创建没有任何规则的模拟传输层
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加匹配指定接口的规则
+    pub fn when(&self, action: impl Into<String>) -> MockRule<'_> {
+        MockRule {
+            transport: self,
+            action: action.into(),
+            service: None,
+            region: None,
+        }
+    }
+
+    /// 获取收到的所有请求
+    pub fn requests(&self) -> Vec<TransportRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// 获取收到的指定接口的请求
+    pub fn requests_for(&self, action: &str) -> Vec<TransportRequest> {
+        self.state
+            .lock()
+            .unwrap()
+            .requests
+            .iter()
+            .filter(|request| request.action == action)
+            .cloned()
+            .collect()
+    }
+
+    /// 清空请求记录
+    pub fn clear_requests(&self) {
+        self.state.lock().unwrap().requests.clear();
+    }
+}
+
+#[async_trait]
+impl Transport for MockTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+        let responder = {
+            let mut state = self.state.lock().unwrap();
+            state.requests.push(request.clone());
+            state
+                .rules
+                .iter()
+                .find(|rule| rule.matches(&request))
+                .map(|rule| rule.responder.clone())
+        };
+        match responder {
+            Some(responder) => responder(&request),
+            None => Err(Error::Other(format!(
+                "MockTransport没有匹配的规则: service={}, action={}, region={}",
+                request.service,
+                request.action,
+                request.region.as_deref().unwrap_or_default()
+            ))),
+        }
+    }
+}
+
+/// 模拟传输层的匹配规则构造器
+///
+/// 调用`respond_*`方法后规则才会生效
+pub struct MockRule<'a> {
+    transport: &'a MockTransport,
+    action: String,
+    service: Option<String>,
+    region: Option<String>,
+}
+
+impl MockRule<'_> {
+    /// 只匹配指定服务的请求
+    pub fn service(mut self, service: impl Into<String>) -> Self {
+        self.service = Some(service.into());
+        self
+    }
+
+    /// 只匹配指定地域的请求
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    /// 返回状态码200和指定的JSON响应体，响应体需要包含外层的`Response`
+    pub fn respond_json(self, body: Value) {
+        self.respond(200, body.to_string());
+    }
+
+    /// 返回腾讯云API错误，如`respond_error("ResourceNotFound.InstanceNotFound", "实例不存在")`
+    pub fn respond_error(self, code: impl Into<String>, message: impl Into<String>) {
+        let body = json!({
+            "Response": {
+                "Error": {"Code": code.into(), "Message": message.into()},
+                "RequestId": MOCK_REQUEST_ID,
+            }
+        });
+        self.respond(200, body.to_string());
+    }
+
+    /// 返回指定的状态码和响应体
+    pub fn respond(self, status: u16, body: impl Into<String>) {
+        let response = TransportResponse::new(status, body);
+        self.respond_with(move |_| Ok(response.clone()));
+    }
+
+    /// 根据请求动态生成响应，返回`Err`可以模拟网络错误
+    pub fn respond_with<F>(self, responder: F)
+    where
+        F: Fn(&TransportRequest) -> Result<TransportResponse> + Send + Sync + 'static,
+    {
+        self.transport.state.lock().unwrap().rules.push(Rule {
+            action: self.action,
+            service: self.service,
+            region: self.region,
+            responder: Arc::new(responder),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::signature::SignatureInfo;
+
+    fn request(action: &str, service: &str, region: Option<&str>) -> TransportRequest {
+        let signature_info = SignatureInfo {
+            method: "POST".to_string(),
+            url: format!("https://{}.tencentcloudapi.com", service),
+            headers: vec![("X-TC-Action".to_string(), action.to_string())],
+            payload: "{}".to_string(),
+        };
+        TransportRequest::new(service, action, "2017-03-12", region, signature_info)
+    }
+
+    #[tokio::test]
+    async fn test_match_and_record() {
+        let mock = MockTransport::new();
+        mock.when("DescribeInstances").region("ap-beijing").respond(200, "beijing");
+        mock.when("DescribeInstances").service("cvm").respond(200, "any");
+
+        let response = mock.send(request("DescribeInstances", "cvm", Some("ap-beijing"))).await.unwrap();
+        assert_eq!(response.body, "beijing");
+        let response = mock.send(request("DescribeInstances", "cvm", Some("ap-guangzhou"))).await.unwrap();
+        assert_eq!(response.body, "any");
+        assert!(mock.send(request("DescribeInstances", "cbs", None)).await.is_err());
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].header("x-tc-action"), Some("DescribeInstances"));
+        assert_eq!(mock.requests_for("RunInstances").len(), 0);
+    }
+
+    #[test]
+    fn test_request_debug_redacts_signature() {
+        let mut request = request("DescribeInstances", "cvm", None);
+        request.headers.push(("Authorization".to_string(), "TC3-HMAC-SHA256 Signature=abcdef".to_string()));
+        request.headers.push(("X-TC-Token".to_string(), "session-token".to_string()));
+
+        let output = format!("{:?}", request);
+        assert!(!output.contains("abcdef"));
+        assert!(!output.contains("session-token"));
+        assert!(output.contains("DescribeInstances"));
+    }
+
+    #[tokio::test]
+    async fn test_respond_with_error() {
+        let mock = MockTransport::new();
+        mock.when("DescribeRegions")
+            .respond_with(|_| Err(Error::Other("connection reset".to_string())));
+        let err = mock.send(request("DescribeRegions", "cvm", None)).await.unwrap_err();
+        assert!(matches!(err, Error::Other(ref message) if message == "connection reset"));
+    }
+}
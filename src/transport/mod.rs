@@ -0,0 +1,122 @@
+//! 传输层
+//!
+//! 客户端通过`Transport`发送签名后的请求。默认使用基于reqwest的`HttpClient`，
+//! 测试时可以替换为`MockTransport`或回放录制文件的`CassetteTransport`，无需访问网络
+
+pub mod cassette;
+pub mod mock;
+
+use async_trait::async_trait;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::utils::http::redact_headers;
+use crate::utils::signature::SignatureInfo;
+
+pub use crate::utils::http::HttpClient;
+pub use cassette::CassetteTransport;
+pub use mock::MockTransport;
+
+/// 发送给传输层的请求
+///
+/// `Debug`输出中`Authorization`和`X-TC-Token`请求头的值替换为`***`
+#[derive(Clone)]
+pub struct TransportRequest {
+    /// 服务名称，如`cvm`
+    pub service: String,
+    /// 接口名称，如`DescribeInstances`
+    pub action: String,
+    /// 接口版本，如`2017-03-12`
+    pub version: String,
+    /// 地域，部分接口不需要地域
+    pub region: Option<String>,
+    /// HTTP请求方法
+    pub method: String,
+    /// 请求URL，GET请求包含查询字符串
+    pub url: String,
+    /// HTTP请求头，包含签名
+    pub headers: Vec<(String, String)>,
+    /// 请求体
+    pub payload: String,
+}
+
+impl TransportRequest {
+    /// 根据接口信息和签名信息创建请求
+    pub fn new(
+        service: impl Into<String>,
+        action: impl Into<String>,
+        version: impl Into<String>,
+        region: Option<&str>,
+        signature_info: SignatureInfo,
+    ) -> Self {
+        Self {
+            service: service.into(),
+            action: action.into(),
+            version: version.into(),
+            region: region.map(str::to_string),
+            method: signature_info.method,
+            url: signature_info.url,
+            headers: signature_info.headers,
+            payload: signature_info.payload,
+        }
+    }
+
+    /// 获取请求头的值，名称不区分大小写
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl fmt::Debug for TransportRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransportRequest")
+            .field("service", &self.service)
+            .field("action", &self.action)
+            .field("version", &self.version)
+            .field("region", &self.region)
+            .field("method", &self.method)
+            .field("url", &self.url)
+            .field("headers", &redact_headers(&self.headers))
+            .field("payload", &self.payload)
+            .finish()
+    }
+}
+
+/// 传输层返回的原始响应
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransportResponse {
+    /// HTTP状态码
+    pub status: u16,
+    /// 响应体
+    pub body: String,
+}
+
+impl TransportResponse {
+    /// 创建响应
+    pub fn new(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: body.into(),
+        }
+    }
+}
+
+/// 传输层接口
+///
+/// 只负责发送请求和返回原始响应，API错误的解析由客户端统一处理
+#[async_trait]
+pub trait Transport: Send + Sync {
+    /// 发送请求
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse>;
+}
+
+#[async_trait]
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+        (**self).send(request).await
+    }
+}
@@ -1,9 +1,13 @@
+use async_trait::async_trait;
 use reqwest::{self, Client, header};
 use serde::de::DeserializeOwned;
 use serde_json::Value;
-use std::time::Duration;
+#[cfg(feature = "tracing")]
+use std::time::Instant;
 
-use crate::error::{Error, Result};
+use crate::config::ClientConfig;
+use crate::error::{Error, ErrorCode, Result};
+use crate::transport::{Transport, TransportRequest, TransportResponse};
 use super::signature::SignatureInfo;
 
 /// HTTP客户端，处理腾讯云API请求
@@ -14,11 +18,17 @@ pub struct HttpClient {
 impl HttpClient {
     /// 创建新的HTTP客户端
     pub fn new() -> Self {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(30))
-            .build()
-            .expect("无法创建HTTP客户端");
-        Self { client }
+        Self::with_config(&ClientConfig::default()).expect("无法创建HTTP客户端")
+    }
+
+    /// 根据客户端配置创建HTTP客户端
+    pub fn with_config(config: &ClientConfig) -> Result<Self> {
+        let mut builder = Client::builder().timeout(config.get_timeout());
+        if let Some(proxy) = config.get_proxy() {
+            builder = builder.proxy(reqwest::Proxy::all(proxy)?);
+        }
+        let client = builder.build()?;
+        Ok(Self { client })
     }
     
     /// 发送请求并解析响应
@@ -26,6 +36,12 @@ impl HttpClient {
     where
         T: DeserializeOwned,
     {
+        let response = self.send_raw(&signature_info).await?;
+        parse_response(response)
+    }
+
+    /// 发送请求并返回原始响应
+    async fn send_raw(&self, signature_info: &SignatureInfo) -> Result<TransportResponse> {
         // 创建请求头
         let mut headers = header::HeaderMap::new();
         for (key, value) in signature_info.headers.iter() {
@@ -42,69 +58,166 @@ impl HttpClient {
             headers.insert(header_name, header_value);
         }
         
-        // 打印请求信息
-        // println!("请求URL: {}", signature_info.url);
-        // println!("请求头: {:?}", headers);
-        // println!("请求体: {}", signature_info.payload);
+        // 记录请求信息，请求体可能包含密码等敏感信息，只记录长度
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            method = %signature_info.method,
+            url = %signature_info.url,
+            headers = ?redact_headers(&signature_info.headers),
+            payload_len = signature_info.payload.len(),
+            "发送请求"
+        );
+        #[cfg(feature = "tracing")]
+        let started = Instant::now();
         
-        // 发送请求
-        let response = self.client
-            .post(&signature_info.url)
-            .headers(headers)
-            .body(signature_info.payload.clone())
-            .send()
-            .await?;
-        
-        // 检查响应状态
-        let status = response.status();
-        println!("响应状态码: {}", status);
+        // 发送请求，GET请求的参数已在URL中
+        let method = reqwest::Method::from_bytes(signature_info.method.as_bytes())
+            .map_err(|e| Error::Other(format!("无效的请求方法{}: {}", signature_info.method, e)))?;
+        let mut request = self.client.request(method, &signature_info.url).headers(headers);
+        if !signature_info.payload.is_empty() {
+            request = request.body(signature_info.payload.clone());
+        }
+        let response = request.send().await?;
         
-        // 获取并打印响应文本
-        let response_text = response.text().await?;
-        // println!("响应内容: {}", response_text);
+        // 获取响应状态和响应文本
+        let status = response.status().as_u16();
+        let body = response.text().await?;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(status, latency_ms = started.elapsed().as_millis() as u64, "收到响应");
         
-        // 首先尝试将响应文本解析为JSON以检查是否有错误
-        let json_value: Value = match serde_json::from_str(&response_text) {
-            Ok(v) => v,
-            Err(e) => {
-                println!("无法解析JSON: {}", e);
-                return Err(Error::SerdeError(e));
-            }
+        Ok(TransportResponse::new(status, body))
+    }
+}
+
+#[async_trait]
+impl Transport for HttpClient {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+        let signature_info = SignatureInfo {
+            method: request.method,
+            url: request.url,
+            headers: request.headers,
+            payload: request.payload,
         };
-        
-        // 检查响应中是否有错误信息
-        if let Some(response) = json_value.get("Response") {
-            if let Some(error) = response.get("Error") {
-                if let (Some(code), Some(message)) = (error.get("Code"), error.get("Message")) {
-                    if let (Some(code_str), Some(message_str)) = (code.as_str(), message.as_str()) {
-                        println!("API错误: {} - {}", code_str, message_str);
-                        return Err(Error::ApiError {
-                            code: code_str.to_string(),
-                            message: message_str.to_string(),
-                        });
-                    }
-                }
-            }
+        self.send_raw(&signature_info).await
+    }
+}
+
+/// 解析传输层返回的原始响应
+///
+/// 响应中包含`Response.Error`时返回`Error::ApiError`，非成功状态码且没有API错误信息时返回`Error::HttpStatusError`
+pub fn parse_response<T>(response: TransportResponse) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    let TransportResponse { status, body: response_text } = response;
+    let is_success = (200..300).contains(&status);
+
+    // 首先尝试将响应文本解析为JSON以检查是否有错误
+    let json_value: Value = match serde_json::from_str(&response_text) {
+        Ok(v) => v,
+        Err(_) if !is_success => {
+            return Err(Error::HttpStatusError {
+                status,
+                message: response_text,
+            });
         }
-        
-        // 从文本重新解析JSON为请求的类型
-        match serde_json::from_str::<T>(&response_text) {
-            Ok(data) => Ok(data),
-            Err(err) => {
-                println!("JSON解析错误: {}", err);
-                Err(Error::SerdeError(err))
+        Err(e) => return Err(Error::SerdeError(e)),
+    };
+    
+    let request_id = json_value
+        .pointer("/Response/RequestId")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    
+    // 检查响应中是否有错误信息
+    if let Some(response) = json_value.get("Response") {
+        if let Some(error) = response.get("Error") {
+            if let (Some(code), Some(message)) = (error.get("Code"), error.get("Message")) {
+                if let (Some(code_str), Some(message_str)) = (code.as_str(), message.as_str()) {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(code = code_str, message = message_str, request_id, "API错误");
+                    return Err(Error::ApiError {
+                        code: ErrorCode::from(code_str),
+                        message: message_str.to_string(),
+                        request_id: request_id.to_string(),
+                        status,
+                    });
+                }
             }
         }
     }
     
-    /// 默认实例
-    pub fn default() -> Self {
-        Self::new()
+    // 非成功状态码且没有API错误信息
+    if !is_success {
+        return Err(Error::HttpStatusError {
+            status,
+            message: response_text,
+        });
     }
+    
+    #[cfg(feature = "tracing")]
+    tracing::debug!(request_id, "请求成功");
+    
+    // 从文本重新解析JSON为请求的类型
+    serde_json::from_value::<T>(json_value).map_err(Error::SerdeError)
+}
+
+/// 需要脱敏的请求头，包含签名和临时凭证令牌，日志、`Debug`输出和录制文件共用
+pub(crate) const SENSITIVE_HEADERS: &[&str] = &["authorization", "x-tc-token"];
+
+/// 生成用于日志和`Debug`输出的请求头，敏感请求头的值替换为`***`
+pub(crate) fn redact_headers(headers: &[(String, String)]) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if SENSITIVE_HEADERS.iter().any(|sensitive| name.eq_ignore_ascii_case(sensitive)) {
+                "***".to_string()
+            } else {
+                value.clone()
+            };
+            (name.clone(), value)
+        })
+        .collect()
 }
 
 impl Default for HttpClient {
     fn default() -> Self {
         Self::new()
     }
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_response() {
+        let value: Value = parse_response(TransportResponse::new(200, r#"{"Response":{"RequestId":"req-1"}}"#)).unwrap();
+        assert_eq!(value["Response"]["RequestId"], "req-1");
+
+        let err = parse_response::<Value>(TransportResponse::new(
+            400,
+            r#"{"Response":{"Error":{"Code":"InvalidParameter","Message":"bad"},"RequestId":"req-2"}}"#,
+        ))
+        .unwrap_err();
+        assert_eq!(err.request_id(), Some("req-2"));
+
+        let err = parse_response::<Value>(TransportResponse::new(502, "Bad Gateway")).unwrap_err();
+        assert!(matches!(err, Error::HttpStatusError { status: 502, .. }));
+    }
+
+    #[test]
+    fn test_redact_headers() {
+        let headers = vec![
+            ("Authorization".to_string(), "TC3-HMAC-SHA256 Credential=AKIDxxx/2024-01-01/cvm/tc3_request".to_string()),
+            ("X-TC-Token".to_string(), "secret-token".to_string()),
+            ("X-TC-Action".to_string(), "DescribeInstances".to_string()),
+        ];
+
+        let redacted = redact_headers(&headers);
+        let value = |name: &str| redacted.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str());
+        assert_eq!(value("Authorization"), Some("***"));
+        assert_eq!(value("X-TC-Token"), Some("***"));
+        assert_eq!(value("X-TC-Action"), Some("DescribeInstances"));
+    }
+}
@@ -3,4 +3,11 @@
 //! 包含签名算法和HTTP请求等通用功能
 
 pub mod signature;
-pub mod http; 
\ No newline at end of file
+pub mod http;
+#[cfg(any(test, feature = "mock-server"))]
+pub(crate) mod local_server;
+pub(crate) mod request_builder;
+pub(crate) mod string_enum;
+
+#[cfg(test)]
+pub(crate) mod test_server; 
\ No newline at end of file
@@ -0,0 +1,144 @@
+//! 请求参数构造器
+//!
+//! 包含必填参数的请求不实现`Default`，通过构造器设置参数，
+//! 在`build()`时检查必填参数，避免发送缺少实例ID等参数的请求
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// 为请求参数生成构造器，`required`中的字段在`build()`时检查是否已设置且不为空
+///
+/// ```text
+/// request_builder! {
+///     /// 启动实例请求参数构造器
+///     StartInstancesRequest => StartInstancesRequestBuilder {
+///         required {
+///             /// 设置实例ID
+///             instance_ids => InstanceIds: Vec<String>,
+///         }
+///         optional {}
+///     }
+/// }
+/// ```
+///
+/// 只启用不使用构造器的服务特性时该宏不会被使用
+#[allow(unused_macros)]
+macro_rules! request_builder {
+    (
+        $(#[$meta:meta])*
+        $request:ident => $builder:ident {
+            required {
+                $($(#[$required_meta:meta])* $required_setter:ident => $required:ident: $required_ty:ty,)*
+            }
+            optional {
+                $($(#[$optional_meta:meta])* $optional_setter:ident => $optional:ident: $optional_ty:ty,)*
+            }
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Default)]
+        pub struct $builder {
+            $($required: Option<$required_ty>,)*
+            $($optional: Option<$optional_ty>,)*
+        }
+
+        impl $request {
+            /// 创建构造器
+            pub fn builder() -> $builder {
+                $builder::default()
+            }
+        }
+
+        impl $builder {
+            $(
+                $(#[$required_meta])*
+                pub fn $required_setter(mut self, value: impl Into<$required_ty>) -> Self {
+                    self.$required = Some(value.into());
+                    self
+                }
+            )*
+
+            $(
+                $(#[$optional_meta])*
+                pub fn $optional_setter(mut self, value: impl Into<$optional_ty>) -> Self {
+                    self.$optional = Some(value.into());
+                    self
+                }
+            )*
+
+            /// 检查必填参数并生成请求
+            pub fn build(self) -> $crate::error::Result<$request> {
+                Ok($request {
+                    $($required: $crate::utils::request_builder::required(stringify!($required), self.$required)?,)*
+                    $($optional: self.$optional,)*
+                })
+            }
+        }
+    };
+}
+
+#[allow(unused_imports)]
+pub(crate) use request_builder;
+
+/// 检查必填参数已设置，字符串和列表为空时视为未设置
+#[allow(dead_code)]
+pub(crate) fn required<T: Serialize>(name: &str, value: Option<T>) -> crate::error::Result<T> {
+    let value = value.ok_or_else(|| crate::error::Error::ParameterError(format!("缺少必填参数{}", name)))?;
+    let blank = match serde_json::to_value(&value) {
+        Ok(Value::String(s)) => s.trim().is_empty(),
+        Ok(Value::Array(items)) => items.is_empty(),
+        _ => false,
+    };
+    if blank {
+        return Err(crate::error::Error::ParameterError(format!("必填参数{}不能为空", name)));
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use serde::Serialize;
+
+    #[derive(Debug, Serialize)]
+    pub struct TestRequest {
+        InstanceIds: Vec<String>,
+        Name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ForceStop: Option<bool>,
+    }
+
+    request_builder! {
+        /// 测试用的构造器
+        TestRequest => TestRequestBuilder {
+            required {
+                instance_ids => InstanceIds: Vec<String>,
+                name => Name: String,
+            }
+            optional {
+                force_stop => ForceStop: bool,
+            }
+        }
+    }
+
+    #[test]
+    fn test_request_builder() {
+        let request = TestRequest::builder()
+            .instance_ids(vec!["ins-1".to_string()])
+            .name("test")
+            .force_stop(true)
+            .build()
+            .unwrap();
+        assert_eq!(request.InstanceIds, ["ins-1"]);
+        assert_eq!(request.ForceStop, Some(true));
+
+        let err = TestRequest::builder().name("test").build().unwrap_err();
+        assert!(matches!(err, Error::ParameterError(ref message) if message == "缺少必填参数InstanceIds"));
+
+        let err = TestRequest::builder().instance_ids(Vec::<String>::new()).name("test").build().unwrap_err();
+        assert!(matches!(err, Error::ParameterError(ref message) if message == "必填参数InstanceIds不能为空"));
+
+        let err = TestRequest::builder().instance_ids(vec!["ins-1".to_string()]).name(" ").build().unwrap_err();
+        assert!(matches!(err, Error::ParameterError(ref message) if message == "必填参数Name不能为空"));
+    }
+}
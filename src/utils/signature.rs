@@ -1,16 +1,91 @@
 use chrono::{DateTime, Utc};
 use hmac::{Hmac, Mac};
 use sha2::{Digest, Sha256};
+use std::fmt;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 type HmacSha256 = Hmac<Sha256>;
 
+/// 签名算法
+const ALGORITHM: &str = "TC3-HMAC-SHA256";
+
+/// 参与签名的请求头
+const SIGNED_HEADERS: &str = "content-type;host";
+
+/// 请求方式
+///
+/// 大部分接口使用默认的JSON请求，部分旧接口需要GET或表单请求
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RequestStyle {
+    /// POST请求，请求体为JSON
+    #[default]
+    Json,
+    /// POST请求，请求体为`application/x-www-form-urlencoded`编码的参数
+    Form,
+    /// GET请求，参数编码后放在查询字符串中
+    Get,
+}
+
+impl RequestStyle {
+    /// 获取HTTP请求方法
+    pub fn method(&self) -> &'static str {
+        match self {
+            RequestStyle::Get => "GET",
+            RequestStyle::Json | RequestStyle::Form => "POST",
+        }
+    }
+
+    /// 获取Content-Type请求头的值
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            RequestStyle::Json => "application/json; charset=utf-8",
+            RequestStyle::Form | RequestStyle::Get => "application/x-www-form-urlencoded",
+        }
+    }
+}
+
+/// 按RFC3986编码，保留`A-Z a-z 0-9 - _ . ~`，其他字节编码为大写十六进制的`%XX`
+pub fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// 按参数名排序并编码为规范查询字符串，如`Limit=10&Offset=0`
+///
+/// GET请求的URL查询字符串和表单请求的请求体都使用该格式，签名时使用相同的字符串
+pub fn canonical_query_string(params: &[(String, String)]) -> String {
+    let mut params: Vec<&(String, String)> = params.iter().collect();
+    params.sort();
+    params
+        .iter()
+        .map(|(key, value)| format!("{}={}", percent_encode(key), percent_encode(value)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
 /// 获取当前UTC日期，格式为YYYY-MM-DD
+///
+/// 签名时应使用`date_of(timestamp)`，从签名时间戳计算日期，避免在UTC零点前后与`X-TC-Timestamp`不一致
+#[deprecated(note = "使用date_of(timestamp)从签名时间戳计算日期")]
 pub fn get_date() -> String {
     let now: DateTime<Utc> = Utc::now();
     now.format("%Y-%m-%d").to_string()
 }
 
+/// 获取时间戳对应的UTC日期，格式为YYYY-MM-DD，签名的凭证范围使用该日期
+pub fn date_of(timestamp: u64) -> String {
+    DateTime::<Utc>::from_timestamp(timestamp as i64, 0)
+        .unwrap_or_default()
+        .format("%Y-%m-%d")
+        .to_string()
+}
+
 /// 获取当前时间戳（秒）
 pub fn get_timestamp() -> u64 {
     SystemTime::now()
@@ -47,11 +122,14 @@ pub struct SignatureBuilder {
     secret_key: String,
     service: String,
     host: String,
+    scheme: String,
     region: String,
     action: String,
     version: String,
     timestamp: u64,
     payload: String,
+    style: RequestStyle,
+    token: Option<String>,
 }
 
 impl SignatureBuilder {
@@ -67,14 +145,23 @@ impl SignatureBuilder {
             secret_key,
             service,
             host,
+            scheme: String::from("https"),
             region: String::new(),
             action: String::new(),
             version: String::new(),
             timestamp: get_timestamp(),
             payload: String::from("{}"),
+            style: RequestStyle::Json,
+            token: None,
         }
     }
 
+    /// 设置请求协议，默认为https
+    pub fn scheme(mut self, scheme: String) -> Self {
+        self.scheme = scheme;
+        self
+    }
+
     /// 设置区域
     pub fn region(mut self, region: String) -> Self {
         self.region = region;
@@ -93,12 +180,27 @@ impl SignatureBuilder {
         self
     }
 
-    /// 设置请求载荷（JSON字符串）
+    /// 设置请求载荷
+    ///
+    /// JSON请求为JSON字符串；表单和GET请求为`canonical_query_string`编码的参数，
+    /// GET请求的载荷会作为URL查询字符串发送
     pub fn payload(mut self, payload: String) -> Self {
         self.payload = payload;
         self
     }
 
+    /// 设置请求方式，默认为JSON请求
+    pub fn style(mut self, style: RequestStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// 设置临时凭证的会话令牌，将通过`X-TC-Token`头传递
+    pub fn token(mut self, token: Option<String>) -> Self {
+        self.token = token;
+        self
+    }
+
     /// 设置时间戳（主要用于测试）
     pub fn timestamp(mut self, timestamp: u64) -> Self {
         self.timestamp = timestamp;
@@ -107,76 +209,171 @@ impl SignatureBuilder {
 
     /// 构建签名和相关HTTP请求头
     pub fn build(&self) -> SignatureInfo {
-        // 获取日期
-        let date = get_date();
+        // 凭证范围的日期必须与时间戳对应的UTC日期一致
+        let date = date_of(self.timestamp);
         let timestamp_str = self.timestamp.to_string();
+        let credential_scope = format!("{}/{}/tc3_request", date, self.service);
+        let signature = self.signature(&date);
+
+        // 拼接Authorization
+        let authorization = format!(
+            "{} Credential={}/{}, SignedHeaders={}, Signature={}",
+            ALGORITHM,
+            self.secret_id,
+            credential_scope,
+            SIGNED_HEADERS,
+            signature
+        );
+
+        // 构建返回信息
+        let mut headers = vec![
+            ("Authorization".to_string(), authorization),
+            ("Content-Type".to_string(), self.style.content_type().to_string()),
+            ("Host".to_string(), self.host.clone()),
+            ("X-TC-Action".to_string(), self.action.clone()),
+            ("X-TC-Timestamp".to_string(), timestamp_str),
+            ("X-TC-Version".to_string(), self.version.clone()),
+            ("X-TC-Region".to_string(), self.region.clone()),
+        ];
+        if let Some(token) = &self.token {
+            headers.push(("X-TC-Token".to_string(), token.clone()));
+        }
+
+        // GET请求的参数放在查询字符串中，请求体为空
+        let (url, payload) = match self.style {
+            RequestStyle::Get if !self.payload.is_empty() => {
+                (format!("{}://{}/?{}", self.scheme, self.host, self.payload), String::new())
+            }
+            RequestStyle::Get => (format!("{}://{}", self.scheme, self.host), String::new()),
+            RequestStyle::Json | RequestStyle::Form => (format!("{}://{}", self.scheme, self.host), self.payload.clone()),
+        };
+
+        SignatureInfo {
+            method: self.style.method().to_string(),
+            url,
+            headers,
+            payload,
+        }
+    }
+
+    /// 校验收到的Authorization请求头
+    ///
+    /// 生成器需要设置与请求相同的密钥、服务、域名、请求方式、载荷和`X-TC-Timestamp`时间戳，
+    /// GET请求的载荷为URL中的查询字符串。
+    /// 密钥ID、凭证范围（日期和服务）、签名的请求头和签名都一致时返回`true`
+    pub fn verify(&self, authorization: &str) -> bool {
+        let Some(parsed) = Authorization::parse(authorization) else {
+            return false;
+        };
+        let date = date_of(self.timestamp);
+        parsed.secret_id == self.secret_id
+            && parsed.date == date
+            && parsed.service == self.service
+            && parsed.signed_headers == SIGNED_HEADERS
+            && constant_time_eq(parsed.signature.as_bytes(), self.signature(&date).as_bytes())
+    }
 
+    /// 计算签名
+    fn signature(&self, date: &str) -> String {
         // 步骤1：拼接规范请求串
-        let http_request_method = "POST";
+        // GET请求的规范查询字符串为载荷，请求体为空；POST请求的规范查询字符串为空
+        let http_request_method = self.style.method();
         let canonical_uri = "/";
-        let canonical_query_string = "";
-        let canonical_headers = format!("content-type:application/json; charset=utf-8\nhost:{}\n", self.host);
-        let signed_headers = "content-type;host";
-        let hashed_request_payload = sha256_hex(&self.payload);
+        let (canonical_query_string, request_payload) = match self.style {
+            RequestStyle::Get => (self.payload.as_str(), ""),
+            RequestStyle::Json | RequestStyle::Form => ("", self.payload.as_str()),
+        };
+        let canonical_headers = format!("content-type:{}\nhost:{}\n", self.style.content_type(), self.host);
+        let hashed_request_payload = sha256_hex(request_payload);
         let canonical_request = format!(
             "{}\n{}\n{}\n{}\n{}\n{}",
             http_request_method,
             canonical_uri,
             canonical_query_string,
             canonical_headers,
-            signed_headers,
+            SIGNED_HEADERS,
             hashed_request_payload
         );
 
         // 步骤2：拼接待签名字符串
-        let algorithm = "TC3-HMAC-SHA256";
         let credential_scope = format!("{}/{}/tc3_request", date, self.service);
         let hashed_canonical_request = sha256_hex(&canonical_request);
         let string_to_sign = format!(
             "{}\n{}\n{}\n{}",
-            algorithm,
-            timestamp_str,
+            ALGORITHM,
+            self.timestamp,
             credential_scope,
             hashed_canonical_request
         );
 
         // 步骤3：计算签名
         let k_key = format!("TC3{}", self.secret_key);
-        let k_date = hmac_sha256(k_key.as_bytes(), &date);
+        let k_date = hmac_sha256(k_key.as_bytes(), date);
         let k_service = hmac_sha256(&k_date, &self.service);
         let k_signing = hmac_sha256(&k_service, "tc3_request");
-        let signature = hmac_sha256_hex(&k_signing, &string_to_sign);
+        hmac_sha256_hex(&k_signing, &string_to_sign)
+    }
+}
 
-        // 步骤4：拼接Authorization
-        let authorization = format!(
-            "{} Credential={}/{}, SignedHeaders={}, Signature={}",
-            algorithm,
-            self.secret_id,
-            credential_scope,
-            signed_headers,
-            signature
-        );
+/// 解析后的Authorization请求头
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Authorization {
+    /// 密钥ID
+    pub secret_id: String,
+    /// 凭证范围的日期，格式为YYYY-MM-DD
+    pub date: String,
+    /// 凭证范围的服务名称
+    pub service: String,
+    /// 参与签名的请求头，如`content-type;host`
+    pub signed_headers: String,
+    /// 签名
+    pub signature: String,
+}
 
-        // 构建返回信息
-        SignatureInfo {
-            url: format!("https://{}", self.host),
-            headers: vec![
-                ("Authorization".to_string(), authorization),
-                ("Content-Type".to_string(), "application/json; charset=utf-8".to_string()),
-                ("Host".to_string(), self.host.clone()),
-                ("X-TC-Action".to_string(), self.action.clone()),
-                ("X-TC-Timestamp".to_string(), timestamp_str),
-                ("X-TC-Version".to_string(), self.version.clone()),
-                ("X-TC-Region".to_string(), self.region.clone()),
-            ],
-            payload: self.payload.clone(),
+impl Authorization {
+    /// 解析`TC3-HMAC-SHA256 Credential=.../tc3_request, SignedHeaders=..., Signature=...`格式的请求头，
+    /// 格式错误时返回`None`
+    pub fn parse(authorization: &str) -> Option<Self> {
+        let rest = authorization.strip_prefix(ALGORITHM)?.trim_start();
+        let (mut credential, mut signed_headers, mut signature) = (None, None, None);
+        for part in rest.split(',').map(str::trim) {
+            let (key, value) = part.split_once('=')?;
+            match key {
+                "Credential" => credential = Some(value),
+                "SignedHeaders" => signed_headers = Some(value),
+                "Signature" => signature = Some(value),
+                _ => return None,
+            }
+        }
+
+        let mut scope = credential?.split('/');
+        let (secret_id, date, service) = (scope.next()?, scope.next()?, scope.next()?);
+        if scope.next()? != "tc3_request" || scope.next().is_some() {
+            return None;
         }
+        Some(Self {
+            secret_id: secret_id.to_string(),
+            date: date.to_string(),
+            service: service.to_string(),
+            signed_headers: signed_headers?.to_string(),
+            signature: signature?.to_string(),
+        })
     }
 }
 
+/// 比较签名，耗时与不一致的位置无关
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
 /// 签名信息和HTTP请求需要的数据
+///
+/// `Debug`输出中`Authorization`和`X-TC-Token`请求头的值替换为`***`
+#[derive(Clone)]
 pub struct SignatureInfo {
-    /// 请求URL
+    /// HTTP请求方法
+    pub method: String,
+    /// 请求URL，GET请求包含查询字符串
     pub url: String,
     /// HTTP请求头
     pub headers: Vec<(String, String)>,
@@ -184,6 +381,17 @@ pub struct SignatureInfo {
     pub payload: String,
 }
 
+impl fmt::Debug for SignatureInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SignatureInfo")
+            .field("method", &self.method)
+            .field("url", &self.url)
+            .field("headers", &crate::utils::http::redact_headers(&self.headers))
+            .field("payload", &self.payload)
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -201,4 +409,135 @@ mod tests {
         let result = hmac_sha256_hex(key, data);
         assert_eq!(result, "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8");
     }
+
+    /// 官方文档签名方法v3中的示例参数
+    fn official_example() -> SignatureBuilder {
+        SignatureBuilder::new(
+            "AKIDz8krbsJ5yKBZQpn74WFkmLPx3EXAMPLE".to_string(),
+            "Gu5t9xGARNpq86cd98joQYCN3EXAMPLE".to_string(),
+            "cvm".to_string(),
+            "cvm.tencentcloudapi.com".to_string(),
+        )
+        .action("DescribeInstances".to_string())
+        .version("2017-03-12".to_string())
+        .region("ap-guangzhou".to_string())
+        .timestamp(1551113065)
+        .payload(r#"{"Limit": 1, "Filters": [{"Values": ["\u672a\u547d\u540d"], "Name": "instance-name"}]}"#.to_string())
+    }
+
+    fn authorization(info: &SignatureInfo) -> &str {
+        info.headers.iter().find(|(k, _)| k == "Authorization").map(|(_, v)| v.as_str()).unwrap()
+    }
+
+    #[test]
+    fn test_official_example() {
+        let builder = official_example();
+        assert_eq!(
+            sha256_hex(&builder.payload),
+            "35e9c5b0e3ae67532d3c9f17ead6c90222632e5b1ff7f6e89887f1398934f064"
+        );
+
+        let info = builder.build();
+        assert_eq!(
+            authorization(&info),
+            "TC3-HMAC-SHA256 Credential=AKIDz8krbsJ5yKBZQpn74WFkmLPx3EXAMPLE/2019-02-25/cvm/tc3_request, \
+             SignedHeaders=content-type;host, \
+             Signature=72e494ea809ad7a8c8f7a4507b9bddcbaa8e581f516e8da2f66e2c5a96525168"
+        );
+        assert!(info.headers.iter().any(|(k, v)| k == "X-TC-Timestamp" && v == "1551113065"));
+    }
+
+    #[test]
+    fn test_date_follows_timestamp() {
+        // 2019-02-25T23:59:59Z和2019-02-26T00:00:00Z
+        assert_eq!(date_of(1551139199), "2019-02-25");
+        assert_eq!(date_of(1551139200), "2019-02-26");
+
+        let info = official_example().timestamp(1551139200).build();
+        assert!(authorization(&info).contains("/2019-02-26/cvm/tc3_request"));
+    }
+
+    #[test]
+    fn test_verify() {
+        let builder = official_example();
+        let header = authorization(&builder.build()).to_string();
+        assert!(builder.verify(&header));
+
+        // 请求体、时间戳或密钥不一致时校验失败
+        assert!(!official_example().payload("{}".to_string()).verify(&header));
+        assert!(!official_example().timestamp(1551113066).verify(&header));
+        let wrong_key = SignatureBuilder::new(
+            "AKIDz8krbsJ5yKBZQpn74WFkmLPx3EXAMPLE".to_string(),
+            "wrong".to_string(),
+            "cvm".to_string(),
+            "cvm.tencentcloudapi.com".to_string(),
+        )
+        .timestamp(1551113065)
+        .payload(builder.payload.clone());
+        assert!(!wrong_key.verify(&header));
+        assert!(!builder.verify("TC3-HMAC-SHA256 Credential=broken"));
+
+        let parsed = Authorization::parse(&header).unwrap();
+        assert_eq!(parsed.secret_id, "AKIDz8krbsJ5yKBZQpn74WFkmLPx3EXAMPLE");
+        assert_eq!(parsed.date, "2019-02-25");
+        assert_eq!(parsed.service, "cvm");
+    }
+
+    #[test]
+    fn test_percent_encode() {
+        assert_eq!(percent_encode("AZaz09-_.~"), "AZaz09-_.~");
+        assert_eq!(percent_encode("a b*c/d+e"), "a%20b%2Ac%2Fd%2Be");
+        assert_eq!(percent_encode("未命名"), "%E6%9C%AA%E5%91%BD%E5%90%8D");
+
+        let params = vec![
+            ("Offset".to_string(), "0".to_string()),
+            ("Filters.0.Values.0".to_string(), "未命名".to_string()),
+            ("Limit".to_string(), "10".to_string()),
+        ];
+        assert_eq!(
+            canonical_query_string(&params),
+            "Filters.0.Values.0=%E6%9C%AA%E5%91%BD%E5%90%8D&Limit=10&Offset=0"
+        );
+    }
+
+    #[test]
+    fn test_get_and_form_requests() {
+        let query = "Limit=10&Offset=0".to_string();
+        let builder = official_example().style(RequestStyle::Get).payload(query.clone());
+        let info = builder.build();
+        assert_eq!(info.method, "GET");
+        assert_eq!(info.url, "https://cvm.tencentcloudapi.com/?Limit=10&Offset=0");
+        assert_eq!(info.payload, "");
+        assert!(info.headers.iter().any(|(k, v)| k == "Content-Type" && v == "application/x-www-form-urlencoded"));
+        let header = authorization(&info).to_string();
+        assert!(builder.verify(&header));
+        // 同样的参数使用POST表单请求时签名不同
+        assert!(!official_example().style(RequestStyle::Form).payload(query.clone()).verify(&header));
+
+        let info = official_example().style(RequestStyle::Form).payload(query.clone()).build();
+        assert_eq!(info.method, "POST");
+        assert_eq!(info.url, "https://cvm.tencentcloudapi.com");
+        assert_eq!(info.payload, query);
+    }
+
+    #[test]
+    fn test_token_header() {
+        let builder = SignatureBuilder::new(
+            "id".to_string(),
+            "key".to_string(),
+            "cvm".to_string(),
+            "cvm.tencentcloudapi.com".to_string(),
+        );
+        let info = builder.build();
+        assert!(!info.headers.iter().any(|(k, _)| k == "X-TC-Token"));
+
+        let info = builder.token(Some("session".to_string())).build();
+        assert!(info.headers.iter().any(|(k, v)| k == "X-TC-Token" && v == "session"));
+
+        // Debug输出不包含签名和令牌
+        let output = format!("{:?}", info);
+        assert!(!output.contains("session"));
+        assert!(!output.contains("Signature="));
+        assert!(output.contains("X-TC-Action"));
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,93 @@
+//! 字符串枚举
+//!
+//! 接口文档中列出取值范围的字符串字段使用枚举表示，
+//! 服务端返回新的取值时保存在`Unknown`中，不会导致反序列化失败
+
+/// 定义与字符串取值一一对应的枚举，并实现序列化、反序列化和`Display`
+///
+/// 只启用不使用枚举的服务特性时该宏不会被使用
+#[allow(unused_macros)]
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $value:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant,)*
+            /// 未识别的取值，保存服务端返回的原始字符串
+            Unknown(String),
+        }
+
+        impl $name {
+            /// 获取接口中使用的字符串取值
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => $name::$variant,)*
+                    _ => $name::Unknown(value.to_string()),
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                Ok($name::from(value.as_str()))
+            }
+        }
+    };
+}
+
+#[allow(unused_imports)]
+pub(crate) use string_enum;
+
+#[cfg(test)]
+mod tests {
+    string_enum! {
+        /// 测试用的枚举
+        enum Color {
+            /// 红色
+            Red = "RED",
+            /// 浅蓝色
+            LightBlue = "LIGHT_BLUE",
+        }
+    }
+
+    #[test]
+    fn test_known_values() {
+        assert_eq!(serde_json::to_string(&Color::LightBlue).unwrap(), r#""LIGHT_BLUE""#);
+        assert_eq!(serde_json::from_str::<Color>(r#""RED""#).unwrap(), Color::Red);
+        assert_eq!(Color::Red.to_string(), "RED");
+    }
+
+    #[test]
+    fn test_unknown_value_roundtrip() {
+        let color: Color = serde_json::from_str(r#""ULTRAVIOLET""#).unwrap();
+        assert_eq!(color, Color::Unknown("ULTRAVIOLET".to_string()));
+        assert_eq!(serde_json::to_string(&color).unwrap(), r#""ULTRAVIOLET""#);
+    }
+}
@@ -0,0 +1,71 @@
+//! 测试用的本地HTTP服务
+//!
+//! 仅用于单元测试，按处理函数返回固定的响应，并记录收到的请求
+
+use std::sync::{Arc, Mutex};
+use tokio::net::{TcpListener, TcpStream};
+
+use super::local_server;
+
+/// 收到的HTTP请求
+pub(crate) use super::local_server::HttpRequest as RecordedRequest;
+
+/// 本地HTTP服务
+pub(crate) struct TestServer {
+    /// 服务地址，如`http://127.0.0.1:12345`
+    pub url: String,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl TestServer {
+    /// 启动本地HTTP服务，处理函数返回状态码和响应体
+    pub async fn start<F>(handler: F) -> Self
+    where
+        F: Fn(&RecordedRequest) -> (u16, String) + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let handler = Arc::new(handler);
+
+        let recorded = requests.clone();
+        tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                let handler = handler.clone();
+                let recorded = recorded.clone();
+                tokio::spawn(async move {
+                    let _ = handle(socket, handler.as_ref(), recorded).await;
+                });
+            }
+        });
+
+        Self { url, requests }
+    }
+
+    /// 获取服务地址中的主机和端口
+    pub fn host(&self) -> &str {
+        self.url.trim_start_matches("http://")
+    }
+
+    /// 获取已收到的请求
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// 读取一个请求并写回响应
+async fn handle<F>(
+    mut socket: TcpStream,
+    handler: &F,
+    recorded: Arc<Mutex<Vec<RecordedRequest>>>,
+) -> std::io::Result<()>
+where
+    F: Fn(&RecordedRequest) -> (u16, String),
+{
+    let Some(request) = local_server::read_request(&mut socket).await? else {
+        return Ok(());
+    };
+    let (status, response_body) = handler(&request);
+    recorded.lock().unwrap().push(request);
+    local_server::write_response(&mut socket, status, &response_body).await
+}
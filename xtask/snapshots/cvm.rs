@@ -0,0 +1,353 @@
+//! 云服务器（cvm 2017-03-12）接口
+//!
+//! 由`cargo xtask codegen`根据官方API描述文件生成，请勿手动修改
+
+use serde::{Deserialize, Serialize};
+
+use crate::action::impl_action;
+use crate::client::TencentCloudClient;
+use crate::error::Result;
+use crate::services::common::ApiResponse;
+use crate::utils::string_enum::string_enum;
+
+string_enum! {
+    /// 状态值
+    pub enum InstanceState {
+        /// 表示创建中
+        Pending = "PENDING",
+        /// 表示创建失败
+        LaunchFailed = "LAUNCH_FAILED",
+        /// 表示运行中
+        Running = "RUNNING",
+        /// 表示关机
+        Stopped = "STOPPED",
+        /// 表示开机中
+        Starting = "STARTING",
+        /// 表示关机中
+        Stopping = "STOPPING",
+        /// 表示重启中
+        Rebooting = "REBOOTING",
+        /// 表示停止待销毁
+        Shutdown = "SHUTDOWN",
+        /// 表示销毁中
+        Terminating = "TERMINATING",
+    }
+}
+
+string_enum! {
+    /// 实例操作
+    pub enum Operation {
+        /// 实例降配操作
+        InstanceDegrade = "INSTANCE_DEGRADE",
+        /// 修改网络带宽计费模式
+        InternetChargeTypeChange = "INTERNET_CHARGE_TYPE_CHANGE",
+    }
+}
+
+string_enum! {
+    /// 自动续费标识
+    pub enum RenewFlag {
+        /// 通知过期且自动续费
+        NotifyAndAutoRenew = "NOTIFY_AND_AUTO_RENEW",
+        /// 通知过期不自动续费
+        NotifyAndManualRenew = "NOTIFY_AND_MANUAL_RENEW",
+        /// 不通知过期不自动续费
+        DisableNotifyAndManualRenew = "DISABLE_NOTIFY_AND_MANUAL_RENEW",
+    }
+}
+
+/// 查询所支持的实例机型族信息请求参数
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DescribeInstanceFamilyConfigsRequest {}
+
+/// 查询所支持的实例机型族信息响应
+#[derive(Debug, Clone, Deserialize)]
+pub struct DescribeInstanceFamilyConfigsResponse {
+    /// 实例机型组配置的列表信息
+    pub InstanceFamilyConfigSet: Vec<InstanceFamilyConfig>,
+
+    /// 唯一请求 ID，由服务端生成，每次请求都会返回（若请求因其他原因未能抵达服务端，则该次请求不会获得 RequestId）。定位问题时需要提供该次请求的 RequestId。
+    pub RequestId: String,
+}
+
+/// 查询所支持的实例机型族信息响应类型
+pub type DescribeInstanceFamilyConfigsResponseType = ApiResponse<DescribeInstanceFamilyConfigsResponse>;
+
+/// 查询实例机型配置请求参数
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DescribeInstanceTypeConfigsRequest {
+    /// zone
+    /// 按照【可用区】进行过滤。
+    /// 类型：String
+    /// 必选：否
+    /// instance-family
+    /// 按照【实例机型系列】进行过滤。
+    /// 类型：String
+    /// 必选：否
+    /// 每次请求的`Filters`的上限为10，`Filter.Values`的上限为1。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub Filters: Option<Vec<Filter>>,
+}
+
+/// 查询实例机型配置响应
+#[derive(Debug, Clone, Deserialize)]
+pub struct DescribeInstanceTypeConfigsResponse {
+    /// 实例机型配置列表。
+    pub InstanceTypeConfigSet: Vec<InstanceTypeConfig>,
+
+    /// 唯一请求 ID，由服务端生成，每次请求都会返回（若请求因其他原因未能抵达服务端，则该次请求不会获得 RequestId）。定位问题时需要提供该次请求的 RequestId。
+    pub RequestId: String,
+}
+
+/// 查询实例机型配置响应类型
+pub type DescribeInstanceTypeConfigsResponseType = ApiResponse<DescribeInstanceTypeConfigsResponse>;
+
+/// 查询实例操作限制请求参数
+#[derive(Debug, Clone, Serialize)]
+pub struct DescribeInstancesOperationLimitRequest {
+    /// 按照一个或者多个实例ID查询，可通过[DescribeInstances](https://cloud.tencent.com/document/api/213/15728)API返回值中的InstanceId获取。实例ID形如：ins-xxxxxxxx。每次请求的实例的上限为100。
+    pub InstanceIds: Vec<String>,
+
+    /// 实例操作。
+    /// INSTANCE_DEGRADE：实例降配操作
+    /// INTERNET_CHARGE_TYPE_CHANGE：修改网络带宽计费模式
+    pub Operation: Operation,
+}
+
+/// 查询实例操作限制响应
+#[derive(Debug, Clone, Deserialize)]
+pub struct DescribeInstancesOperationLimitResponse {
+    /// 该参数表示调整配置操作（降配）限制次数查询。
+    pub InstanceOperationLimitSet: Vec<OperationCountLimit>,
+
+    /// 唯一请求 ID，由服务端生成，每次请求都会返回（若请求因其他原因未能抵达服务端，则该次请求不会获得 RequestId）。定位问题时需要提供该次请求的 RequestId。
+    pub RequestId: String,
+}
+
+/// 查询实例操作限制响应类型
+pub type DescribeInstancesOperationLimitResponseType = ApiResponse<DescribeInstancesOperationLimitResponse>;
+
+/// 查看实例状态列表请求参数
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DescribeInstancesStatusRequest {
+    /// 按照一个或者多个实例ID查询。实例ID形如：`ins-11112222`。每次请求的实例的上限为100。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub InstanceIds: Option<Vec<String>>,
+
+    /// 偏移量，默认为0。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub Offset: Option<i64>,
+
+    /// 返回数量，默认为20，最大值为100。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub Limit: Option<i64>,
+}
+
+/// 查看实例状态列表响应
+#[derive(Debug, Clone, Deserialize)]
+pub struct DescribeInstancesStatusResponse {
+    /// 符合条件的实例状态数量。
+    pub TotalCount: i64,
+
+    /// [InstanceStatus](https://cloud.tencent.com/document/api/213/15753#InstanceStatus) 列表。
+    pub InstanceStatusSet: Vec<InstanceStatus>,
+
+    /// 唯一请求 ID，由服务端生成，每次请求都会返回（若请求因其他原因未能抵达服务端，则该次请求不会获得 RequestId）。定位问题时需要提供该次请求的 RequestId。
+    pub RequestId: String,
+}
+
+/// 查看实例状态列表响应类型
+pub type DescribeInstancesStatusResponseType = ApiResponse<DescribeInstancesStatusResponse>;
+
+/// 续费实例请求参数
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RenewInstancesRequest {
+    /// 一个或多个待操作的实例ID。可通过[`DescribeInstances`](https://cloud.tencent.com/document/api/213/15728)接口返回值中的`InstanceId`获取。每次请求批量实例的上限为100。
+    pub InstanceIds: Vec<String>,
+
+    /// 预付费模式，即包年包月相关参数设置。通过该参数可以指定包年包月实例的续费时长、是否设置自动续费等属性。包年包月实例该参数为必传参数。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub InstanceChargePrepaid: Option<InstanceChargePrepaid>,
+
+    /// 是否续费弹性数据盘。取值范围：
+    /// true：表示续费包年包月实例同时续费其挂载的弹性数据盘
+    /// false：表示续费包年包月实例同时不再续费其挂载的弹性数据盘
+    /// 默认取值：true。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub RenewPortableDataDisk: Option<bool>,
+}
+
+/// 续费实例响应
+#[derive(Debug, Clone, Deserialize)]
+pub struct RenewInstancesResponse {
+    /// 唯一请求 ID，由服务端生成，每次请求都会返回（若请求因其他原因未能抵达服务端，则该次请求不会获得 RequestId）。定位问题时需要提供该次请求的 RequestId。
+    pub RequestId: String,
+}
+
+/// 续费实例响应类型
+pub type RenewInstancesResponseType = ApiResponse<RenewInstancesResponse>;
+
+/// 描述键值对过滤器，用于条件过滤查询。例如过滤ID、名称、状态等
+/// * 若存在多个`Filter`时，`Filter`间的关系为逻辑与（`AND`）关系。
+/// * 若同一个`Filter`存在多个`Values`，同一`Filter`下`Values`间的关系为逻辑或（`OR`）关系。
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Filter {
+    /// 需要过滤的字段。
+    pub Name: String,
+
+    /// 字段的过滤值。
+    pub Values: Vec<String>,
+}
+
+/// 描述了实例的计费模式
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct InstanceChargePrepaid {
+    /// 购买实例的时长，单位：月。取值范围：1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 24, 36, 48, 60。
+    pub Period: i64,
+
+    /// 自动续费标识。取值范围：
+    /// NOTIFY_AND_AUTO_RENEW：通知过期且自动续费
+    /// NOTIFY_AND_MANUAL_RENEW：通知过期不自动续费
+    /// DISABLE_NOTIFY_AND_MANUAL_RENEW：不通知过期不自动续费
+    /// 默认取值：NOTIFY_AND_MANUAL_RENEW。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub RenewFlag: Option<RenewFlag>,
+}
+
+/// 描述实例机型配置信息
+#[derive(Debug, Clone, Deserialize)]
+pub struct InstanceFamilyConfig {
+    /// 机型族名称的中文全称。
+    pub InstanceFamilyName: String,
+
+    /// 机型族名称的英文简称。
+    pub InstanceFamily: String,
+}
+
+/// 描述实例的状态。
+#[derive(Debug, Clone, Deserialize)]
+pub struct InstanceStatus {
+    /// 实例`ID`。
+    pub InstanceId: String,
+
+    /// 状态值。取值范围：
+    /// PENDING：表示创建中
+    /// LAUNCH_FAILED：表示创建失败
+    /// RUNNING：表示运行中
+    /// STOPPED：表示关机
+    /// STARTING：表示开机中
+    /// STOPPING：表示关机中
+    /// REBOOTING：表示重启中
+    /// SHUTDOWN：表示停止待销毁
+    /// TERMINATING：表示销毁中。
+    pub InstanceState: InstanceState,
+}
+
+/// 批量实例属性
+#[derive(Debug, Clone, Deserialize)]
+pub struct InstanceTypeConfig {
+    /// 可用区。
+    pub Zone: String,
+
+    /// 实例机型。
+    pub InstanceType: String,
+
+    /// 实例机型系列。
+    pub InstanceFamily: String,
+
+    /// GPU核数，单位：核。
+    pub GPU: i64,
+
+    /// CPU核数，单位：核。
+    pub CPU: i64,
+
+    /// 内存容量，单位：`GB`。
+    pub Memory: i64,
+
+    /// FPGA核数，单位：核。
+    pub FPGA: i64,
+
+    /// 实例机型映射的物理GPU卡数，单位：卡。vGPU卡型小于1，直通卡型大于等于1。vGPU是通过分片虚拟化技术，将物理GPU卡重新划分，同一块GPU卡经虚拟化分割后可分配至不同的实例使用。直通卡型会将GPU设备直接挂载给实例使用。
+    pub GpuCount: Option<f64>,
+}
+
+/// 描述单台实例操作次数限制
+#[derive(Debug, Clone, Deserialize)]
+pub struct OperationCountLimit {
+    /// 实例操作。取值范围：
+    /// `INSTANCE_DEGRADE`：降配操作
+    /// `INTERNET_CHARGE_TYPE_CHANGE`：修改网络带宽计费模式
+    pub Operation: Operation,
+
+    /// 实例ID。
+    pub InstanceId: String,
+
+    /// 当前已使用次数，如果返回值为-1表示该操作无次数限制。
+    pub CurrentCount: i64,
+
+    /// 操作次数最高额度，如果返回值为-1表示该操作无次数限制，如果返回值为0表示不支持调整配置。
+    pub LimitCount: i64,
+}
+
+impl_action! {
+    "cvm", "2017-03-12";
+    DescribeInstanceFamilyConfigsRequest => DescribeInstanceFamilyConfigsResponse = "DescribeInstanceFamilyConfigs";
+    DescribeInstanceTypeConfigsRequest => DescribeInstanceTypeConfigsResponse = "DescribeInstanceTypeConfigs";
+    DescribeInstancesOperationLimitRequest => DescribeInstancesOperationLimitResponse = "DescribeInstancesOperationLimit";
+    DescribeInstancesStatusRequest => DescribeInstancesStatusResponse = "DescribeInstancesStatus";
+    RenewInstancesRequest => RenewInstancesResponse = "RenewInstances";
+}
+
+/// 云服务器服务
+pub struct CvmService<'a> {
+    client: &'a TencentCloudClient,
+}
+
+impl<'a> CvmService<'a> {
+    /// 创建新的云服务器服务
+    pub fn new(client: &'a TencentCloudClient) -> Self {
+        Self { client }
+    }
+
+    /// 查询所支持的实例机型族信息
+    ///
+    /// 本接口（DescribeInstanceFamilyConfigs）查询当前用户和地域所支持的机型族列表信息。
+    pub async fn describe_instance_family_configs(&self, request: &DescribeInstanceFamilyConfigsRequest, region: &str) -> Result<DescribeInstanceFamilyConfigsResponseType> {
+        self.client.execute(request, Some(region)).await
+    }
+
+    /// 查询实例机型配置
+    ///
+    /// 本接口（DescribeInstanceTypeConfigs）用于查询实例机型配置。
+    /// * 可以根据`zone`、`instance-family`、`instance-type`来查询实例机型配置。过滤条件详见过滤器[`Filter`](https://cloud.tencent.com/document/api/213/15753#Filter)。
+    /// * 如果参数为空，返回指定地域的所有实例机型配置。
+    pub async fn describe_instance_type_configs(&self, request: &DescribeInstanceTypeConfigsRequest, region: &str) -> Result<DescribeInstanceTypeConfigsResponseType> {
+        self.client.execute(request, Some(region)).await
+    }
+
+    /// 查询实例操作限制
+    ///
+    /// 本接口（DescribeInstancesOperationLimit）用于查询实例操作限制。
+    /// * 目前支持调整配置操作限制次数查询。
+    pub async fn describe_instances_operation_limit(&self, request: &DescribeInstancesOperationLimitRequest, region: &str) -> Result<DescribeInstancesOperationLimitResponseType> {
+        self.client.execute(request, Some(region)).await
+    }
+
+    /// 查看实例状态列表
+    ///
+    /// 本接口 (DescribeInstancesStatus) 用于查询一个或多个实例的状态。
+    /// * 可以根据实例`ID`来查询实例的状态。
+    /// * 如果参数为空，返回当前用户一定数量（Limit所指定的数量，默认为20）的实例状态。
+    pub async fn describe_instances_status(&self, request: &DescribeInstancesStatusRequest, region: &str) -> Result<DescribeInstancesStatusResponseType> {
+        self.client.execute(request, Some(region)).await
+    }
+
+    /// 续费实例
+    ///
+    /// 本接口 (RenewInstances) 用于续费包年包月实例。
+    /// * 只支持操作包年包月实例。
+    /// * 续费时请确保账户余额充足。可通过[`DescribeAccountBalance`](https://cloud.tencent.com/document/product/555/20253)接口查询账户余额。
+    pub async fn renew_instances(&self, request: &RenewInstancesRequest, region: &str) -> Result<RenewInstancesResponseType> {
+        self.client.execute(request, Some(region)).await
+    }
+}
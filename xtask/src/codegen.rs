@@ -0,0 +1,461 @@
+//! 代码生成
+//!
+//! 根据API描述文件生成请求和响应结构、字符串枚举、`Action`实现以及服务方法。
+//! 生成的代码放在SDK的`src/services`下，依赖SDK中的`impl_action!`和`string_enum!`宏
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use crate::spec::{MemberSpec, ObjectSpec, Spec};
+
+/// 结构的使用场景
+#[derive(Debug, Clone, Copy, Default)]
+struct Usage {
+    /// 出现在请求参数中
+    input: bool,
+    /// 出现在响应中
+    output: bool,
+}
+
+/// 从字段说明中识别出的字符串枚举
+#[derive(Debug, Clone, PartialEq)]
+struct EnumDef {
+    name: String,
+    doc: String,
+    /// 取值和说明
+    values: Vec<(String, String)>,
+}
+
+/// 生成过程中的上下文
+struct Generator<'a> {
+    spec: &'a Spec,
+    usages: BTreeMap<&'a str, Usage>,
+    /// 枚举定义，按名称排序
+    enums: BTreeMap<String, EnumDef>,
+    /// 使用枚举的字段：(结构名称, 字段名称) -> 枚举名称
+    enum_fields: BTreeMap<(&'a str, &'a str), String>,
+}
+
+/// 生成指定接口的代码，`actions`为空时生成所有未下线的接口
+pub fn generate(spec: &Spec, actions: &[String]) -> Result<String, String> {
+    for action in actions {
+        if !spec.actions.contains_key(action) {
+            return Err(format!("描述文件中没有接口{}", action));
+        }
+    }
+    let selected: Vec<(&String, &crate::spec::ActionSpec)> = spec
+        .actions
+        .iter()
+        .filter(|(name, action)| action.status != "deprecated" && (actions.is_empty() || actions.contains(name)))
+        .collect();
+    if selected.is_empty() {
+        return Err("没有可生成的接口".to_string());
+    }
+
+    let mut generator = Generator {
+        spec,
+        usages: BTreeMap::new(),
+        enums: BTreeMap::new(),
+        enum_fields: BTreeMap::new(),
+    };
+    for (_, action) in &selected {
+        generator.mark(&action.input, true)?;
+        generator.mark(&action.output, false)?;
+    }
+    generator.collect_enums();
+
+    let mut out = String::new();
+    generator.write_header(&mut out);
+    for definition in generator.enums.values() {
+        write_enum(&mut out, definition);
+    }
+
+    // 先按接口输出请求和响应，再输出其他结构
+    let mut written = BTreeSet::new();
+    for (_, action) in &selected {
+        if written.insert(action.input.as_str()) {
+            generator.write_object(&mut out, &action.input, &[format!("{}请求参数", action.name)]);
+        }
+        if written.insert(action.output.as_str()) {
+            generator.write_object(&mut out, &action.output, &[format!("{}响应", action.name)]);
+            writeln!(out, "/// {}响应类型", action.name).unwrap();
+            writeln!(out, "pub type {}Type = ApiResponse<{}>;\n", action.output, action.output).unwrap();
+        }
+    }
+    for name in generator.usages.keys() {
+        if written.insert(name) {
+            generator.write_object(&mut out, name, &doc_lines(&spec.objects[*name].document));
+        }
+    }
+
+    generator.write_service(&mut out, &selected);
+    Ok(out)
+}
+
+impl<'a> Generator<'a> {
+    /// 标记结构及其引用的结构的使用场景
+    fn mark(&mut self, name: &'a str, input: bool) -> Result<(), String> {
+        let object = self.object(name)?;
+        let usage = self.usages.entry(name).or_default();
+        let marked = if input { &mut usage.input } else { &mut usage.output };
+        if *marked {
+            return Ok(());
+        }
+        *marked = true;
+
+        for member in object.members.iter().filter(|member| !member.disabled) {
+            if primitive_type(&member.member).is_none() {
+                self.mark(&member.member, input)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn object(&self, name: &str) -> Result<&'a ObjectSpec, String> {
+        self.spec.objects.get(name).ok_or_else(|| format!("描述文件中没有结构{}", name))
+    }
+
+    /// 识别字段说明中列出取值范围的字符串字段
+    ///
+    /// 同名字段的取值相同时共用一个枚举，取值不同或与结构重名时使用`<结构名称><字段名称>`
+    fn collect_enums(&mut self) {
+        for &object_name in self.usages.keys() {
+            for member in self.spec.objects[object_name].members.iter().filter(|member| !member.disabled) {
+                if member.member != "string" {
+                    continue;
+                }
+                let Some(values) = parse_enum_values(&member.document) else {
+                    continue;
+                };
+                let definition = EnumDef {
+                    name: member.name.clone(),
+                    doc: enum_doc(&member.document, &member.name),
+                    values,
+                };
+
+                let mut name = member.name.clone();
+                let same_values = |existing: &EnumDef| {
+                    existing.values.iter().map(|(value, _)| value).eq(definition.values.iter().map(|(value, _)| value))
+                };
+                if self.spec.objects.contains_key(&name) || self.enums.get(&name).is_some_and(|existing| !same_values(existing)) {
+                    name = format!("{}{}", object_name, member.name);
+                    if self.spec.objects.contains_key(&name) || self.enums.contains_key(&name) {
+                        continue;
+                    }
+                }
+                self.enums.entry(name.clone()).or_insert(EnumDef { name: name.clone(), ..definition });
+                self.enum_fields.insert((object_name, member.name.as_str()), name);
+            }
+        }
+    }
+
+    /// 结构是否可以派生`Default`：必填字段不能是枚举或不能派生`Default`的结构
+    fn defaultable(&self, name: &str) -> bool {
+        let usage = self.usages[name];
+        self.spec.objects[name].members.iter().filter(|member| !member.disabled).all(|member| {
+            !is_required(member, usage)
+                || member.kind == "list"
+                || (!self.enum_fields.contains_key(&(name, member.name.as_str()))
+                    && (primitive_type(&member.member).is_some() || self.defaultable(&member.member)))
+        })
+    }
+
+    /// 字段的Rust类型，不包含`Option`
+    fn field_type(&self, object_name: &str, member: &MemberSpec) -> String {
+        let base = match self.enum_fields.get(&(object_name, member.name.as_str())) {
+            Some(name) => name.clone(),
+            None => primitive_type(&member.member).map(str::to_string).unwrap_or_else(|| member.member.clone()),
+        };
+        if member.kind == "list" {
+            format!("Vec<{}>", base)
+        } else {
+            base
+        }
+    }
+
+    fn write_header(&self, out: &mut String) {
+        let metadata = &self.spec.metadata;
+        writeln!(out, "//! {}（{} {}）接口", metadata.service_name_cn, metadata.service_short_name, metadata.api_version).unwrap();
+        writeln!(out, "//!").unwrap();
+        writeln!(out, "//! 由`cargo xtask codegen`根据官方API描述文件生成，请勿手动修改\n").unwrap();
+
+        let serialize = self.usages.values().any(|usage| usage.input);
+        let deserialize = self.usages.values().any(|usage| usage.output);
+        match (deserialize, serialize) {
+            (true, true) => writeln!(out, "use serde::{{Deserialize, Serialize}};\n").unwrap(),
+            (true, false) => writeln!(out, "use serde::Deserialize;\n").unwrap(),
+            (false, true) => writeln!(out, "use serde::Serialize;\n").unwrap(),
+            (false, false) => {}
+        }
+        writeln!(out, "use crate::action::impl_action;").unwrap();
+        writeln!(out, "use crate::client::TencentCloudClient;").unwrap();
+        writeln!(out, "use crate::error::Result;").unwrap();
+        writeln!(out, "use crate::services::common::ApiResponse;").unwrap();
+        if !self.enums.is_empty() {
+            writeln!(out, "use crate::utils::string_enum::string_enum;").unwrap();
+        }
+        writeln!(out).unwrap();
+    }
+
+    fn write_object(&self, out: &mut String, name: &str, doc: &[String]) {
+        let usage = self.usages[name];
+        let mut derives = vec!["Debug", "Clone"];
+        if usage.input && self.defaultable(name) {
+            derives.push("Default");
+        }
+        if usage.input {
+            derives.push("Serialize");
+        }
+        if usage.output {
+            derives.push("Deserialize");
+        }
+
+        write_doc(out, "", doc);
+        writeln!(out, "#[derive({})]", derives.join(", ")).unwrap();
+        let members: Vec<&MemberSpec> = self.spec.objects[name].members.iter().filter(|member| !member.disabled).collect();
+        if members.is_empty() {
+            writeln!(out, "pub struct {} {{}}\n", name).unwrap();
+            return;
+        }
+
+        writeln!(out, "pub struct {} {{", name).unwrap();
+        for (index, member) in members.iter().enumerate() {
+            if index > 0 {
+                writeln!(out).unwrap();
+            }
+            write_doc(out, "    ", &doc_lines(&member.document));
+            let field_type = self.field_type(name, member);
+            if is_required(member, usage) {
+                writeln!(out, "    pub {}: {},", member.name, field_type).unwrap();
+            } else {
+                if usage.input {
+                    writeln!(out, "    #[serde(skip_serializing_if = \"Option::is_none\")]").unwrap();
+                }
+                writeln!(out, "    pub {}: Option<{}>,", member.name, field_type).unwrap();
+            }
+        }
+        writeln!(out, "}}\n").unwrap();
+    }
+
+    fn write_service(&self, out: &mut String, actions: &[(&String, &crate::spec::ActionSpec)]) {
+        let metadata = &self.spec.metadata;
+        writeln!(out, "impl_action! {{").unwrap();
+        writeln!(out, "    \"{}\", \"{}\";", metadata.service_short_name, metadata.api_version).unwrap();
+        for (name, action) in actions {
+            writeln!(out, "    {} => {} = \"{}\";", action.input, action.output, name).unwrap();
+        }
+        writeln!(out, "}}\n").unwrap();
+
+        let service = format!("{}Service", pascal_case(&metadata.service_short_name));
+        writeln!(out, "/// {}服务", metadata.service_name_cn).unwrap();
+        writeln!(out, "pub struct {}<'a> {{", service).unwrap();
+        writeln!(out, "    client: &'a TencentCloudClient,").unwrap();
+        writeln!(out, "}}\n").unwrap();
+        writeln!(out, "impl<'a> {}<'a> {{", service).unwrap();
+        writeln!(out, "    /// 创建新的{}服务", metadata.service_name_cn).unwrap();
+        writeln!(out, "    pub fn new(client: &'a TencentCloudClient) -> Self {{").unwrap();
+        writeln!(out, "        Self {{ client }}").unwrap();
+        writeln!(out, "    }}").unwrap();
+        for (name, action) in actions {
+            writeln!(out).unwrap();
+            let mut lines = vec![action.name.clone()];
+            let document = doc_lines(&action.document);
+            if !document.is_empty() {
+                lines.push(String::new());
+                lines.extend(document);
+            }
+            write_doc(out, "    ", &lines);
+            writeln!(
+                out,
+                "    pub async fn {}(&self, request: &{}, region: &str) -> Result<{}Type> {{",
+                snake_case(name),
+                action.input,
+                action.output
+            )
+            .unwrap();
+            writeln!(out, "        self.client.execute(request, Some(region)).await").unwrap();
+            writeln!(out, "    }}").unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+    }
+}
+
+/// 输出枚举定义
+fn write_enum(out: &mut String, definition: &EnumDef) {
+    writeln!(out, "string_enum! {{").unwrap();
+    writeln!(out, "    /// {}", definition.doc).unwrap();
+    writeln!(out, "    pub enum {} {{", definition.name).unwrap();
+    for (value, doc) in &definition.values {
+        if !doc.is_empty() {
+            writeln!(out, "        /// {}", doc).unwrap();
+        }
+        writeln!(out, "        {} = \"{}\",", pascal_case(value), value).unwrap();
+    }
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}\n").unwrap();
+}
+
+/// 输出文档注释，空行输出为`///`
+fn write_doc(out: &mut String, indent: &str, lines: &[String]) {
+    for line in lines {
+        if line.is_empty() {
+            writeln!(out, "{}///", indent).unwrap();
+        } else {
+            writeln!(out, "{}/// {}", indent, line).unwrap();
+        }
+    }
+}
+
+/// 作为请求参数时按`required`判断，作为响应字段时按`output_required`和`value_allowed_null`判断，
+/// 两种场景都使用的结构需要同时满足。响应中的`RequestId`总是返回
+fn is_required(member: &MemberSpec, usage: Usage) -> bool {
+    (!usage.input || member.required)
+        && (!usage.output || member.name == "RequestId" || (member.output_required && !member.value_allowed_null))
+}
+
+/// 基础类型对应的Rust类型，结构名称返回`None`
+fn primitive_type(member: &str) -> Option<&'static str> {
+    match member {
+        "string" | "binary" => Some("String"),
+        "int" | "int32" | "int64" => Some("i64"),
+        "uint32" | "uint64" => Some("u64"),
+        "float" | "double" => Some("f64"),
+        "bool" => Some("bool"),
+        _ => None,
+    }
+}
+
+/// 把接口说明中的HTML标签转换为文本行
+fn doc_lines(text: &str) -> Vec<String> {
+    let text = ["<br/>", "<br>", "<li>", "</li>", "</p>"]
+        .iter()
+        .fold(text.to_string(), |text, tag| text.replace(tag, "\n"));
+
+    // 去掉剩余的标签，如<strong>、<p style="...">
+    let mut plain = String::with_capacity(text.len());
+    let mut rest = text.as_str();
+    while let Some(start) = rest.find('<') {
+        plain.push_str(&rest[..start]);
+        let tag = &rest[start + 1..];
+        let is_tag = tag.trim_start_matches('/').starts_with(|c: char| c.is_ascii_alphabetic());
+        match tag.find('>') {
+            Some(end) if is_tag => rest = &tag[end + 1..],
+            _ => {
+                plain.push('<');
+                rest = tag;
+            }
+        }
+    }
+    plain.push_str(rest);
+
+    plain
+        .lines()
+        .map(|line| line.trim().trim_start_matches('>').trim().to_string())
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// 解析说明中`<li>取值：说明`形式的取值列表，取值必须为大写字母、数字和下划线
+fn parse_enum_values(doc: &str) -> Option<Vec<(String, String)>> {
+    let mut values = Vec::new();
+    for item in doc.split("<li>").skip(1) {
+        let end = ["</li>", "<br", "\n"].iter().filter_map(|pattern| item.find(pattern)).min().unwrap_or(item.len());
+        let (value, description) = item[..end].split_once('：').or_else(|| item[..end].split_once(':'))?;
+        let value = value.trim().trim_matches('`');
+        let valid = value.starts_with(|c: char| c.is_ascii_uppercase())
+            && value.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+        if !valid || values.iter().any(|(existing, _): &(String, String)| pascal_case(existing) == pascal_case(value)) {
+            return None;
+        }
+        values.push((value.to_string(), description.trim().trim_end_matches('。').to_string()));
+    }
+    (!values.is_empty()).then_some(values)
+}
+
+/// 枚举说明取字段说明的第一句，如`实例操作。取值范围：`取`实例操作`
+fn enum_doc(doc: &str, name: &str) -> String {
+    let first = doc_lines(doc).into_iter().next().unwrap_or_default();
+    let end = ["。", "取值范围", "："].iter().filter_map(|pattern| first.find(pattern)).min().unwrap_or(first.len());
+    let doc = first[..end].trim();
+    if doc.is_empty() { name.to_string() } else { doc.to_string() }
+}
+
+/// 转换为大驼峰，如`INSTANCE_DEGRADE`转换为`InstanceDegrade`，`cvm`转换为`Cvm`
+fn pascal_case(value: &str) -> String {
+    value
+        .split(['_', '-', '.'])
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+            std::iter::once(first).chain(chars.map(|c| c.to_ascii_lowercase())).collect::<String>()
+        })
+        .collect()
+}
+
+/// 转换为蛇形命名，连续的大写字母视为一个单词，如`AssumeRoleWithSAML`转换为`assume_role_with_saml`
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut result = String::with_capacity(name.len() + 4);
+    for (index, &c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && index > 0 {
+            let previous = chars[index - 1];
+            let next_is_lower = chars.get(index + 1).is_some_and(|next| next.is_ascii_lowercase());
+            if previous.is_ascii_lowercase() || previous.is_ascii_digit() || (previous.is_ascii_uppercase() && next_is_lower) {
+                result.push('_');
+            }
+        }
+        result.push(c.to_ascii_lowercase());
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn test_names() {
+        assert_eq!(snake_case("DescribeInstancesOperationLimit"), "describe_instances_operation_limit");
+        assert_eq!(snake_case("AssumeRoleWithSAML"), "assume_role_with_saml");
+        assert_eq!(snake_case("DescribeCVMInstances"), "describe_cvm_instances");
+        assert_eq!(pascal_case("NOTIFY_AND_AUTO_RENEW"), "NotifyAndAutoRenew");
+        assert_eq!(pascal_case("cvm"), "Cvm");
+    }
+
+    #[test]
+    fn test_parse_docs() {
+        let doc = "状态值。取值范围：<br><li>PENDING：表示创建中<br></li><li>`RUNNING`：表示运行中。</li>";
+        assert_eq!(
+            parse_enum_values(doc),
+            Some(vec![
+                ("PENDING".to_string(), "表示创建中".to_string()),
+                ("RUNNING".to_string(), "表示运行中".to_string()),
+            ])
+        );
+        assert_eq!(enum_doc(doc, "InstanceState"), "状态值");
+        assert_eq!(doc_lines(doc), ["状态值。取值范围：", "PENDING：表示创建中", "`RUNNING`：表示运行中。"]);
+        assert_eq!(parse_enum_values("<li>true：续费数据盘</li>"), None);
+        assert_eq!(doc_lines("<p style=\"x\">类型：<strong>String</strong></p>a<b"), ["类型：String", "a<b"]);
+    }
+
+    #[test]
+    fn test_cvm_snapshot() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let spec = Spec::load(&dir.join("specs/cvm-2017-03-12.json")).unwrap();
+        let code = generate(&spec, &[]).unwrap();
+
+        let snapshot = dir.join("snapshots/cvm.rs");
+        if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+            std::fs::write(&snapshot, &code).unwrap();
+        }
+        let expected = std::fs::read_to_string(&snapshot).unwrap();
+        assert!(code == expected, "生成结果与快照{}不一致，确认无误后设置UPDATE_SNAPSHOTS=1重新生成", snapshot.display());
+
+        let subset = generate(&spec, &["RenewInstances".to_string()]).unwrap();
+        assert!(subset.contains("pub struct InstanceChargePrepaid"));
+        assert!(!subset.contains("DescribeInstancesStatus"));
+        assert!(generate(&spec, &["DescribeImages".to_string()]).is_err());
+    }
+}
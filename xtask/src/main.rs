@@ -0,0 +1,82 @@
+//! 开发工具
+//!
+//! 用法: cargo xtask codegen --spec <描述文件> [--out <输出文件>] [--actions 接口1,接口2]
+
+mod codegen;
+mod spec;
+
+use std::collections::HashMap;
+use std::env;
+use std::path::Path;
+use std::process::ExitCode;
+
+use spec::Spec;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().collect();
+    let params = parse_args(&args);
+    if args.get(1).map(String::as_str) != Some("codegen") || params.contains_key("help") {
+        print_usage();
+        return ExitCode::SUCCESS;
+    }
+
+    match run_codegen(&params) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("错误: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// 根据描述文件生成代码，输出到文件或标准输出
+fn run_codegen(params: &HashMap<String, String>) -> Result<(), String> {
+    let spec_path = params.get("spec").ok_or("缺少--spec参数")?;
+    let spec = Spec::load(Path::new(spec_path))?;
+    let actions: Vec<String> = params
+        .get("actions")
+        .map(|s| s.split(',').map(|a| a.trim().to_string()).filter(|a| !a.is_empty()).collect())
+        .unwrap_or_default();
+
+    let code = codegen::generate(&spec, &actions)?;
+    match params.get("out") {
+        Some(out) => {
+            std::fs::write(out, code).map_err(|e| format!("写入{}失败: {}", out, e))?;
+            println!("已生成: {}", out);
+        }
+        None => print!("{}", code),
+    }
+    Ok(())
+}
+
+// 解析命令行参数
+fn parse_args(args: &[String]) -> HashMap<String, String> {
+    let mut params = HashMap::new();
+    let mut i = 1;
+
+    while i < args.len() {
+        if let Some(key) = args[i].strip_prefix("--") {
+            if i + 1 < args.len() && !args[i + 1].starts_with("--") {
+                params.insert(key.to_string(), args[i + 1].clone());
+                i += 2;
+            } else {
+                params.insert(key.to_string(), "true".to_string());
+                i += 1;
+            }
+        } else {
+            i += 1;
+        }
+    }
+
+    params
+}
+
+// 打印使用方法
+fn print_usage() {
+    println!("用法: cargo xtask codegen [参数]");
+    println!("\n可用参数:");
+    println!("  --help                 显示此帮助信息");
+    println!("  --spec <文件>          官方API描述文件，如tencentcloud-cli中的services/cvm/v20170312/api.json");
+    println!("  --out <文件>           输出文件 (默认: 标准输出)");
+    println!("  --actions <接口列表>   只生成指定的接口，用逗号分隔 (默认: 所有未下线的接口)");
+}
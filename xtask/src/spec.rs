@@ -0,0 +1,95 @@
+//! 官方API描述文件
+//!
+//! 与tencentcloud-cli中`services/<产品>/<版本>/api.json`的格式相同
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// 产品某个版本的API描述
+#[derive(Debug, Deserialize)]
+pub struct Spec {
+    /// 接口，按接口名称排序
+    pub actions: BTreeMap<String, ActionSpec>,
+    /// 产品信息
+    pub metadata: Metadata,
+    /// 请求、响应和数据结构，按名称排序
+    pub objects: BTreeMap<String, ObjectSpec>,
+}
+
+impl Spec {
+    /// 读取并解析描述文件
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path).map_err(|e| format!("读取{}失败: {}", path.display(), e))?;
+        serde_json::from_str(&content).map_err(|e| format!("解析{}失败: {}", path.display(), e))
+    }
+}
+
+/// 产品信息
+#[derive(Debug, Deserialize)]
+pub struct Metadata {
+    /// 接口版本，如`2017-03-12`
+    #[serde(rename = "apiVersion")]
+    pub api_version: String,
+    /// 产品中文名称，如`云服务器`
+    #[serde(rename = "serviceNameCN")]
+    pub service_name_cn: String,
+    /// 产品简称，如`cvm`
+    #[serde(rename = "serviceShortName")]
+    pub service_short_name: String,
+}
+
+/// 接口
+#[derive(Debug, Deserialize)]
+pub struct ActionSpec {
+    /// 接口中文名称
+    pub name: String,
+    /// 接口说明
+    #[serde(default)]
+    pub document: String,
+    /// 请求参数结构名称
+    pub input: String,
+    /// 响应结构名称
+    pub output: String,
+    /// 接口状态，`online`或`deprecated`
+    #[serde(default)]
+    pub status: String,
+}
+
+/// 数据结构
+#[derive(Debug, Deserialize)]
+pub struct ObjectSpec {
+    /// 结构说明
+    #[serde(default)]
+    pub document: String,
+    /// 字段列表
+    #[serde(default)]
+    pub members: Vec<MemberSpec>,
+}
+
+/// 字段
+#[derive(Debug, Deserialize)]
+pub struct MemberSpec {
+    /// 字段名称
+    pub name: String,
+    /// 字段类型：`string`、`int`、`float`、`bool`、`binary`、`object`或`list`
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// 基础类型（如`int64`）、结构名称或列表元素类型
+    pub member: String,
+    /// 字段说明
+    #[serde(default)]
+    pub document: String,
+    /// 作为请求参数时是否必填
+    #[serde(default)]
+    pub required: bool,
+    /// 作为响应字段时是否一定返回
+    #[serde(default)]
+    pub output_required: bool,
+    /// 作为响应字段时是否可能为null
+    #[serde(default)]
+    pub value_allowed_null: bool,
+    /// 是否已停用
+    #[serde(default)]
+    pub disabled: bool,
+}